@@ -1,13 +1,46 @@
-use crate::state::AppState;
+use crate::state::{AppState, StartupPhase};
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tauri::{AppHandle, Emitter, Manager, Runtime};
 
 static COLD_START_DONE: AtomicBool = AtomicBool::new(false);
 static EVENTS_EMITTED: AtomicBool = AtomicBool::new(false);
 
+/// Settings key holding the phase timings of the last completed startup.
+pub const LAST_STARTUP_TIMINGS_KEY: &str = "debug.lastStartupTimings";
+
+/// Records how long a startup phase took and logs it as a cold start trace marker.
+pub fn record_startup_phase(app: &AppHandle, name: &str, duration: Duration) {
+    let state = app.state::<AppState>();
+    let duration_ms = duration.as_millis() as u64;
+    let index = state.push_startup_phase(StartupPhase {
+        name: name.to_string(),
+        duration_ms,
+    });
+    log::info!(
+        "=== COLD START TRACE === [{}] {} took {}ms",
+        index,
+        name,
+        duration_ms
+    );
+}
+
+/// Persists the phases recorded so far for the debug screen. Called once the cold start
+/// has finished, whether it succeeded or not.
+fn persist_startup_timings(app: &AppHandle) {
+    let timings =
+        serde_json::to_value(app.state::<AppState>().startup_timings()).unwrap_or_default();
+    if let Err(e) = crate::commands::settings::set_config_value(
+        app.clone(),
+        LAST_STARTUP_TIMINGS_KEY.to_string(),
+        timings,
+    ) {
+        log::warn!("Failed to persist startup timings: {}", e);
+    }
+}
+
 /// Performs cold start initialization, ensuring it only runs once.
-pub fn run_cold_start<R: Runtime>(app: AppHandle<R>) {
+pub fn run_cold_start(app: AppHandle) {
     // If already done, just re-emit the success events so late listeners receive them.
     if COLD_START_DONE.swap(true, Ordering::SeqCst) {
         log::info!("Cold start previously completed.");
@@ -35,17 +68,22 @@ pub fn run_cold_start<R: Runtime>(app: AppHandle<R>) {
         let state = app.state::<AppState>();
         log::info!("Getting AppState for cold start initialization");
         
+        let started = Instant::now();
         match crate::commands::installed::get_installed_packages_full(app.clone(), state).await {
             Ok(pkgs) => {
                 log::info!("Prefetched {} installed packages", pkgs.len());
+                record_startup_phase(&app, "prefetch installed packages", started.elapsed());
 
                 // Warm the search manifest cache.
                 log::info!("Warming search manifest cache...");
+                let started = Instant::now();
                 if let Err(e) = crate::commands::search::warm_manifest_cache(app.clone()).await {
                     log::error!("Failed to warm search manifest cache: {}", e);
                 } else {
                     log::info!("Search manifest cache warmed successfully");
                 }
+                record_startup_phase(&app, "warm manifest cache", started.elapsed());
+                persist_startup_timings(&app);
 
                 // Emit events with retry logic
                 log::info!("Emitting cold start success events");
@@ -55,6 +93,12 @@ pub fn run_cold_start<R: Runtime>(app: AppHandle<R>) {
             }
             Err(e) => {
                 log::error!("Failed to prefetch installed packages: {}", e);
+                record_startup_phase(
+                    &app,
+                    "prefetch installed packages (failed)",
+                    started.elapsed(),
+                );
+                persist_startup_timings(&app);
                 // On failure, reset the flag to allow a retry on the next page load.
                 COLD_START_DONE.store(false, Ordering::SeqCst);
                 EVENTS_EMITTED.store(false, Ordering::SeqCst);
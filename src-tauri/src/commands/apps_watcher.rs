@@ -0,0 +1,120 @@
+//! Watches the Scoop `apps` directory so the installed packages cache stays fresh when
+//! Scoop is used from the command line.
+use crate::commands::installed::{get_installed_packages_full, invalidate_installed_cache};
+use crate::state::AppState;
+use notify::event::ModifyKind;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+use tokio::sync::mpsc;
+
+/// Quiet period after the last change before the cache is invalidated, so bulk
+/// operations only cause a single rescan.
+const DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// Whether an event adds, removes or renames an entry of the watched directory.
+fn is_relevant(event: &Event) -> bool {
+    matches!(
+        event.kind,
+        EventKind::Create(_) | EventKind::Remove(_) | EventKind::Modify(ModifyKind::Name(_))
+    )
+}
+
+/// Starts watching `apps/` of the current Scoop root, replacing any running watcher.
+///
+/// Once changes settle the installed cache is invalidated and rescanned, which emits
+/// `installed-cache-updated` if the installed packages actually changed.
+pub(crate) fn start_watching(app: &AppHandle) -> Result<(), String> {
+    let state = app.state::<AppState>();
+    let apps_path = state.scoop_path().join("apps");
+    if !apps_path.is_dir() {
+        return Err(format!(
+            "Apps directory does not exist: {}",
+            apps_path.display()
+        ));
+    }
+
+    let (tx, mut rx) = mpsc::unbounded_channel::<()>();
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| match res {
+        Ok(event) if is_relevant(&event) => {
+            let _ = tx.send(());
+        }
+        Ok(_) => {}
+        Err(e) => log::warn!("Apps watcher error: {}", e),
+    })
+    .map_err(|e| format!("Failed to create apps watcher: {}", e))?;
+    watcher
+        .watch(&apps_path, RecursiveMode::NonRecursive)
+        .map_err(|e| format!("Failed to watch {}: {}", apps_path.display(), e))?;
+
+    // Replacing the watcher drops the old one, which closes its channel and ends its task
+    *state.apps_watcher.lock().unwrap() = Some(watcher);
+    log::info!(
+        "Watching {} for installed package changes",
+        apps_path.display()
+    );
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        while rx.recv().await.is_some() {
+            // Wait until no further change arrives within the debounce window
+            loop {
+                match tokio::time::timeout(DEBOUNCE, rx.recv()).await {
+                    Ok(Some(())) => continue,
+                    Ok(None) => return,
+                    Err(_) => break,
+                }
+            }
+
+            let state = app.state::<AppState>();
+            if state.is_shutting_down() {
+                return;
+            }
+            log::info!("Apps directory changed, refreshing installed packages");
+            invalidate_installed_cache(state.clone()).await;
+            if let Err(e) = get_installed_packages_full(app.clone(), state).await {
+                log::warn!("Failed to rescan installed packages: {}", e);
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Restarts the watcher if one is running, e.g. after the Scoop root changed.
+pub(crate) fn restart_if_running(app: &AppHandle) {
+    let running = app
+        .state::<AppState>()
+        .apps_watcher
+        .lock()
+        .unwrap()
+        .is_some();
+    if running {
+        if let Err(e) = start_watching(app) {
+            log::warn!("Failed to restart apps watcher: {}", e);
+            *app.state::<AppState>().apps_watcher.lock().unwrap() = None;
+        }
+    }
+}
+
+/// Starts the apps directory watcher.
+#[tauri::command]
+pub fn start_apps_watcher(app: AppHandle) -> Result<(), String> {
+    start_watching(&app)
+}
+
+/// Stops the apps directory watcher. Returns `false` if it was not running.
+#[tauri::command]
+pub fn stop_apps_watcher(app: AppHandle) -> bool {
+    let stopped = app
+        .state::<AppState>()
+        .apps_watcher
+        .lock()
+        .unwrap()
+        .take()
+        .is_some();
+    if stopped {
+        log::info!("Stopped apps watcher");
+    }
+    stopped
+}
@@ -3,10 +3,15 @@ use crate::commands::installed::get_installed_packages_full;
 use crate::commands::powershell;
 use crate::commands::settings;
 use crate::state::AppState;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use tauri::{AppHandle, Runtime, State};
 
+/// When true, `trigger_auto_cleanup` never runs, regardless of the cleanup settings.
+pub const AUTO_CLEANUP_DISABLED_KEY: &str = "cleanup.autoCleanupDisabled";
+/// Result of the last automatic cleanup, stored as an `AutoCleanupRun`.
+const LAST_AUTO_CLEANUP_KEY: &str = "cleanup.lastAutoRun";
+
 /// Settings for automatic cleanup operations.
 #[derive(Debug, Deserialize)]
 pub struct CleanupSettings {
@@ -20,19 +25,42 @@ pub struct CleanupSettings {
     pub preserve_version_count: usize,
 }
 
+/// A completed automatic cleanup.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoCleanupRun {
+    /// Unix timestamp (seconds) when the cleanup finished.
+    pub timestamp: u64,
+    /// Number of old version directories removed.
+    pub items_cleaned: usize,
+}
+
+/// Whether automatic cleanup will run and what it did last time.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoCleanupStatus {
+    /// True when cleanup is enabled in the cleanup settings and not disabled entirely.
+    pub enabled: bool,
+    /// Whether `cleanup.autoCleanupDisabled` is set.
+    pub disabled: bool,
+    pub last_run: Option<u64>,
+    pub items_cleaned: Option<usize>,
+}
+
 /// Runs the auto cleanup operation silently in the background based on user settings.
 ///
 /// This function is designed to be called after package operations (install, update, uninstall)
 /// to automatically clean up old versions and/or cache without user intervention.
+/// Returns the number of removed version directories.
 #[tauri::command]
 pub async fn run_auto_cleanup<R: Runtime>(
     app: AppHandle<R>,
     state: State<'_, AppState>,
     settings: CleanupSettings,
-) -> Result<(), String> {
+) -> Result<usize, String> {
     if !settings.auto_cleanup_enabled {
         log::debug!("Auto cleanup is disabled, skipping");
-        return Ok(());
+        return Ok(0);
     }
 
     log::info!("Running auto cleanup with settings: {:?}", settings);
@@ -60,14 +88,15 @@ pub async fn run_auto_cleanup<R: Runtime>(
     );
 
     // Run cleanup operations based on settings
-    let scoop_path = state.scoop_path();
+    let scoop_path = crate::commands::scoop::apps_root(&app, &state);
+    let mut removed_versions = 0;
 
     if settings.cleanup_old_versions && !regular_packages.is_empty() {
         log::info!(
             "Running auto cleanup of old versions (preserving {} versions)",
             settings.preserve_version_count
         );
-        cleanup_old_versions_smart(
+        removed_versions = cleanup_old_versions_smart(
             &scoop_path,
             &regular_packages,
             settings.preserve_version_count,
@@ -80,20 +109,26 @@ pub async fn run_auto_cleanup<R: Runtime>(
         cleanup_cache_for_packages(&regular_packages).await?;
     }
 
-    log::info!("Auto cleanup completed successfully");
-    Ok(())
+    crate::commands::doctor::disk_usage::invalidate_disk_usage(&state).await;
+    log::info!(
+        "Auto cleanup completed successfully, removed {} old versions",
+        removed_versions
+    );
+    Ok(removed_versions)
 }
 
 /// Cleans up old versions of packages while preserving the most recent N versions.
 ///
 /// This function reads the version directories for each package and removes the oldest
-/// versions while keeping the specified number of recent versions.
+/// versions while keeping the specified number of recent versions. Returns the number
+/// of removed directories.
 async fn cleanup_old_versions_smart(
     scoop_path: &PathBuf,
     packages: &[String],
     keep_count: usize,
-) -> Result<(), String> {
+) -> Result<usize, String> {
     let apps_path = scoop_path.join("apps");
+    let mut removed = 0;
 
     for package_name in packages {
         let package_path = apps_path.join(package_name);
@@ -110,11 +145,12 @@ async fn cleanup_old_versions_smart(
                 versions_to_remove.len()
             );
 
-            remove_specific_versions(scoop_path, package_name, &versions_to_remove).await;
+            removed +=
+                remove_specific_versions(scoop_path, package_name, &versions_to_remove).await;
         }
     }
 
-    Ok(())
+    Ok(removed)
 }
 
 fn get_versions_to_remove(
@@ -150,8 +186,14 @@ fn get_versions_to_remove(
     }
 }
 
-async fn remove_specific_versions(scoop_path: &PathBuf, package_name: &str, versions: &[String]) {
+/// Removes the given version directories, returning how many were removed.
+async fn remove_specific_versions(
+    scoop_path: &PathBuf,
+    package_name: &str,
+    versions: &[String],
+) -> usize {
     let package_dir = scoop_path.join("apps").join(package_name);
+    let mut removed = 0;
 
     for version in versions {
         let version_dir = package_dir.join(version);
@@ -165,8 +207,10 @@ async fn remove_specific_versions(scoop_path: &PathBuf, package_name: &str, vers
             );
         } else {
             log::debug!("Successfully removed version {}", version);
+            removed += 1;
         }
     }
+    removed
 }
 
 /// Cleans up the cache for specified packages.
@@ -208,7 +252,13 @@ async fn cleanup_cache_for_packages(packages: &[String]) -> Result<(), String> {
 /// This reads the cleanup settings from the store and runs the cleanup if enabled.
 ///
 /// This function is designed to be called after operations like install, update, or uninstall.
-pub async fn trigger_auto_cleanup<R: Runtime>(app: AppHandle<R>, state: State<'_, AppState>) {
+/// Each completed run is recorded for `get_auto_cleanup_status`.
+pub async fn trigger_auto_cleanup(app: AppHandle, state: State<'_, AppState>) {
+    if is_auto_cleanup_disabled(&app) {
+        log::debug!("Auto cleanup is disabled entirely");
+        return;
+    }
+
     // Read cleanup settings from the store
     let cleanup_settings = match read_cleanup_settings(&app) {
         Ok(settings) => settings,
@@ -227,11 +277,67 @@ pub async fn trigger_auto_cleanup<R: Runtime>(app: AppHandle<R>, state: State<'_
     log::info!("Triggering auto cleanup in background");
 
     // Run cleanup directly - it's already async and won't block
-    if let Err(e) = run_auto_cleanup(app, state, cleanup_settings).await {
-        log::warn!("Auto cleanup failed: {}", e);
+    match run_auto_cleanup(app.clone(), state, cleanup_settings).await {
+        Ok(items_cleaned) => {
+            let run = AutoCleanupRun {
+                timestamp: std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map(|d| d.as_secs())
+                    .unwrap_or(0),
+                items_cleaned,
+            };
+            let value = serde_json::to_value(&run).unwrap_or_default();
+            if let Err(e) =
+                settings::set_config_value(app, LAST_AUTO_CLEANUP_KEY.to_string(), value)
+            {
+                log::warn!("Failed to record auto cleanup run: {}", e);
+            }
+        }
+        Err(e) => log::warn!("Auto cleanup failed: {}", e),
     }
 }
 
+/// Reads `cleanup.autoCleanupDisabled`.
+fn is_auto_cleanup_disabled<R: Runtime>(app: &AppHandle<R>) -> bool {
+    settings::get_config_value(app.clone(), AUTO_CLEANUP_DISABLED_KEY.to_string())
+        .ok()
+        .flatten()
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+/// Reports whether automatic cleanup is enabled and when it last ran.
+#[tauri::command]
+pub fn get_auto_cleanup_status<R: Runtime>(app: AppHandle<R>) -> AutoCleanupStatus {
+    let disabled = is_auto_cleanup_disabled(&app);
+    let configured = read_cleanup_settings(&app)
+        .map(|settings| settings.auto_cleanup_enabled)
+        .unwrap_or(false);
+    let last_run = settings::get_config_value(app, LAST_AUTO_CLEANUP_KEY.to_string())
+        .ok()
+        .flatten()
+        .and_then(|v| serde_json::from_value::<AutoCleanupRun>(v).ok());
+
+    AutoCleanupStatus {
+        enabled: configured && !disabled,
+        disabled,
+        last_run: last_run.as_ref().map(|run| run.timestamp),
+        items_cleaned: last_run.map(|run| run.items_cleaned),
+    }
+}
+
+/// Disables or re-enables automatic cleanup entirely, for users who prefer to clean
+/// up manually.
+#[tauri::command]
+pub fn set_auto_cleanup_disabled(app: AppHandle, disabled: bool) -> Result<(), String> {
+    log::info!("Setting auto cleanup disabled: {}", disabled);
+    settings::set_config_value(
+        app,
+        AUTO_CLEANUP_DISABLED_KEY.to_string(),
+        serde_json::json!(disabled),
+    )
+}
+
 /// Reads cleanup settings from the persistent store.
 fn read_cleanup_settings<R: Runtime>(app: &AppHandle<R>) -> Result<CleanupSettings, String> {
     let get_val = |key: &str| {
@@ -276,7 +382,7 @@ fn read_cleanup_settings<R: Runtime>(app: &AppHandle<R>) -> Result<CleanupSettin
 
 /// Compares two version strings using semantic version logic.
 /// Returns std::cmp::Ordering::Less if a < b, Greater if a > b, Equal if same.
-fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
+pub(crate) fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
     // Split version strings by dots and compare each part as numbers
     let a_parts: Vec<u32> = a
         .split('.')
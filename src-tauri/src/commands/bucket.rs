@@ -3,8 +3,10 @@ use crate::models::BucketInfo;
 use crate::state::AppState;
 use crate::utils;
 use git2::Repository;
+use serde::Serialize;
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use tauri::{AppHandle, Runtime, State};
 
 /// Checks if a directory is a Git repository by looking for .git directory.
@@ -213,3 +215,90 @@ pub async fn get_bucket_manifests<R: Runtime>(
     );
     Ok(manifests)
 }
+
+/// One bucket's definition of an app that is defined in several buckets.
+#[derive(Serialize, Debug, Clone)]
+pub struct BucketDefinition {
+    pub bucket: String,
+    pub version: Option<String>,
+}
+
+/// An app defined in more than one bucket.
+#[derive(Serialize, Debug, Clone)]
+pub struct AppConflict {
+    pub name: String,
+    pub definitions: Vec<BucketDefinition>,
+}
+
+/// Returns the directory holding a bucket's manifests: `bucket/` if present, as Scoop
+/// does, otherwise the bucket root.
+fn manifests_dir(bucket_path: &Path) -> PathBuf {
+    let subdir = bucket_path.join("bucket");
+    if subdir.is_dir() {
+        subdir
+    } else {
+        bucket_path.to_path_buf()
+    }
+}
+
+/// Finds apps that are defined in more than one bucket, along with each bucket's version.
+#[tauri::command]
+pub async fn find_bucket_conflicts(state: State<'_, AppState>) -> Result<Vec<AppConflict>, String> {
+    let buckets_path = state.scoop_path().join("buckets");
+    if !buckets_path.is_dir() {
+        return Ok(vec![]);
+    }
+
+    tokio::task::spawn_blocking(move || {
+        // app name (lowercase) -> (bucket, manifest path)
+        let mut by_app: BTreeMap<String, Vec<(String, PathBuf)>> = BTreeMap::new();
+        let bucket_dirs = fs::read_dir(&buckets_path)
+            .map_err(|e| format!("Failed to read buckets directory: {}", e))?
+            .filter_map(Result::ok)
+            .filter(|entry| entry.path().is_dir());
+
+        for bucket in bucket_dirs {
+            let bucket_name = bucket.file_name().to_string_lossy().to_string();
+            let Ok(entries) = fs::read_dir(manifests_dir(&bucket.path())) else {
+                continue;
+            };
+            for path in entries.flatten().map(|entry| entry.path()) {
+                if path.extension().and_then(|s| s.to_str()) != Some("json") {
+                    continue;
+                }
+                if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
+                    by_app
+                        .entry(stem.to_lowercase())
+                        .or_default()
+                        .push((bucket_name.clone(), path.clone()));
+                }
+            }
+        }
+
+        let conflicts: Vec<AppConflict> = by_app
+            .into_iter()
+            .filter(|(_, definitions)| definitions.len() > 1)
+            .map(|(name, mut definitions)| {
+                definitions.sort_by(|a, b| a.0.cmp(&b.0));
+                AppConflict {
+                    name,
+                    definitions: definitions
+                        .into_iter()
+                        .map(|(bucket, path)| BucketDefinition {
+                            bucket,
+                            version: fs::read_to_string(&path)
+                                .ok()
+                                .and_then(|c| serde_json::from_str::<serde_json::Value>(&c).ok())
+                                .and_then(|m| m.get("version")?.as_str().map(str::to_string)),
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+
+        log::info!("Found {} apps defined in multiple buckets", conflicts.len());
+        Ok(conflicts)
+    })
+    .await
+    .map_err(|e| format!("Failed to scan buckets: {}", e))?
+}
@@ -2,11 +2,28 @@ use git2::{Cred, CredentialType, FetchOptions, RemoteCallbacks, Repository};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
-use tauri::command;
+use tauri::{command, AppHandle, Runtime, State, Window};
 
+use crate::commands::scoop::ScoopOp;
+use crate::state::AppState;
 use crate::commands::search::invalidate_manifest_cache;
+use crate::commands::uninstall::execute_package_operation;
 use crate::utils;
 
+// Buckets scoop can add by name alone, used when its own buckets.json is unavailable
+const KNOWN_BUCKETS_FALLBACK: &[&str] = &[
+    "main",
+    "extras",
+    "versions",
+    "nirsoft",
+    "sysinternals",
+    "php",
+    "nerd-fonts",
+    "nonportable",
+    "java",
+    "games",
+];
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BucketInstallOptions {
     pub name: String,
@@ -21,6 +38,12 @@ pub struct BucketInstallResult {
     pub bucket_name: String,
     pub bucket_path: Option<String>,
     pub manifest_count: Option<u32>,
+    /// Number of commits fetched by an update (0 for other operations).
+    #[serde(default)]
+    pub commits_pulled: u32,
+    /// Whether an update actually changed the bucket's contents.
+    #[serde(default)]
+    pub changed: bool,
 }
 
 // Get the buckets directory path
@@ -132,6 +155,8 @@ async fn install_bucket_internal(
             bucket_name: bucket_name.clone(),
             bucket_path: Some(get_bucket_path(&bucket_name)?.to_string_lossy().to_string()),
             manifest_count: None,
+            commits_pulled: 0,
+            changed: false,
         });
     }
 
@@ -179,6 +204,8 @@ async fn install_bucket_internal(
                 bucket_name: bucket_name.clone(),
                 bucket_path: Some(bucket_path.to_string_lossy().to_string()),
                 manifest_count: Some(manifest_count),
+                commits_pulled: 0,
+                changed: false,
             })
         }
         Err(e) => {
@@ -209,6 +236,8 @@ pub async fn install_bucket(options: BucketInstallOptions) -> Result<BucketInsta
                 bucket_name: String::new(),
                 bucket_path: None,
                 manifest_count: None,
+                commits_pulled: 0,
+                changed: false,
             })
         }
     }
@@ -232,6 +261,8 @@ pub async fn validate_bucket_install(
                 bucket_name: name,
                 bucket_path: None,
                 manifest_count: None,
+                commits_pulled: 0,
+                changed: false,
             })
         }
     };
@@ -249,6 +280,8 @@ pub async fn validate_bucket_install(
                 bucket_name: name,
                 bucket_path: None,
                 manifest_count: None,
+                commits_pulled: 0,
+                changed: false,
             })
         }
     };
@@ -280,12 +313,54 @@ pub async fn validate_bucket_install(
         bucket_name,
         bucket_path,
         manifest_count: None,
+        commits_pulled: 0,
+        changed: false,
     })
 }
 
+const UPDATE_IN_PROGRESS_MSG: &str = "A bucket update is already in progress";
+
+/// Number of extra attempts for a bucket update that failed with a network error.
+pub const UPDATE_RETRIES_KEY: &str = "buckets.updateRetries";
+const DEFAULT_UPDATE_RETRIES: u64 = 2;
+/// Upper bound for `buckets.updateRetries`, whatever the stored value.
+const MAX_UPDATE_RETRIES: u64 = 5;
+/// Delay before the first retry; doubled for each following attempt.
+const RETRY_BASE_DELAY_SECS: u64 = 2;
+/// Longest delay between two attempts.
+const MAX_RETRY_DELAY_SECS: u64 = 60;
+
+/// Prefix of the per-bucket keys recording the last successful update of each bucket.
+pub(crate) const BUCKET_LAST_UPDATE_PREFIX: &str = "buckets.lastUpdate.";
+
+// Fragments of git/transport errors that indicate a transient network problem
+const NETWORK_ERROR_PATTERNS: &[&str] = &[
+    "timed out",
+    "timeout",
+    "could not resolve",
+    "failed to resolve",
+    "failed to connect",
+    "connection refused",
+    "connection reset",
+    "network",
+    "unexpected eof",
+    "early eof",
+    "http 5",
+];
+
 // Command to update a bucket (git pull)
 #[command]
-pub async fn update_bucket(_app: tauri::AppHandle, bucket_name: String) -> Result<BucketInstallResult, String> {
+pub async fn update_bucket(
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+    bucket_name: String,
+) -> Result<BucketInstallResult, String> {
+    // Refuse instead of waiting, as a scheduled run may hold the lock for a while
+    let _guard = state
+        .bucket_update_lock
+        .try_lock()
+        .map_err(|_| UPDATE_IN_PROGRESS_MSG.to_string())?;
+
     log::info!("Updating bucket: {}", bucket_name);
 
     let bucket_path = get_bucket_path(&bucket_name)?;
@@ -297,6 +372,8 @@ pub async fn update_bucket(_app: tauri::AppHandle, bucket_name: String) -> Resul
             bucket_name: bucket_name.clone(),
             bucket_path: None,
             manifest_count: None,
+            commits_pulled: 0,
+            changed: false,
         };
 
         return Ok(result);
@@ -313,6 +390,8 @@ pub async fn update_bucket(_app: tauri::AppHandle, bucket_name: String) -> Resul
             bucket_name: bucket_name.clone(),
             bucket_path: Some(bucket_path.to_string_lossy().to_string()),
             manifest_count: None,
+            commits_pulled: 0,
+            changed: false,
         };
 
         return Ok(result);
@@ -325,6 +404,10 @@ pub async fn update_bucket(_app: tauri::AppHandle, bucket_name: String) -> Resul
         .await
         .map_err(|e| e.to_string())??;
 
+    if let Err(e) = record_bucket_updates(app, std::slice::from_ref(&result)) {
+        log::warn!("Failed to record bucket update timestamp: {}", e);
+    }
+
     Ok(result)
 }
 
@@ -345,6 +428,8 @@ fn update_bucket_sync(
                         bucket_name: bucket_name.to_string(),
                         bucket_path: Some(bucket_path.to_string_lossy().to_string()),
                         manifest_count: None,
+                        commits_pulled: 0,
+                        changed: false,
                     });
                 }
             };
@@ -383,6 +468,8 @@ fn update_bucket_sync(
                                 bucket_name: bucket_name.to_string(),
                                 bucket_path: Some(bucket_path.to_string_lossy().to_string()),
                                 manifest_count: None,
+                                commits_pulled: 0,
+                                changed: false,
                             });
                         }
                     };
@@ -409,9 +496,17 @@ fn update_bucket_sync(
                                             bucket_path.to_string_lossy().to_string(),
                                         ),
                                         manifest_count: Some(manifest_count),
+                                        commits_pulled: 0,
+                                        changed: false,
                                     });
                                 }
 
+                                let commits_pulled = count_new_commits(
+                                    &repo,
+                                    local_commit.id(),
+                                    remote_commit.id(),
+                                );
+
                                 // Perform fast-forward merge
                                 let mut checkout_builder = git2::build::CheckoutBuilder::new();
                                 checkout_builder.force();
@@ -428,9 +523,10 @@ fn update_bucket_sync(
                                 let manifest_count = utils::count_manifests(bucket_path);
 
                                 log::info!(
-                                    "Successfully updated bucket '{}' with {} manifests",
+                                    "Successfully updated bucket '{}' with {} manifests ({} new commits)",
                                     bucket_name,
-                                    manifest_count
+                                    manifest_count,
+                                    commits_pulled
                                 );
 
                                 Ok(BucketInstallResult {
@@ -442,6 +538,8 @@ fn update_bucket_sync(
                                     bucket_name: bucket_name.to_string(),
                                     bucket_path: Some(bucket_path.to_string_lossy().to_string()),
                                     manifest_count: Some(manifest_count),
+                                    commits_pulled,
+                                    changed: true,
                                 })
                             }
                             Err(_) => Ok(BucketInstallResult {
@@ -453,6 +551,8 @@ fn update_bucket_sync(
                                 bucket_name: bucket_name.to_string(),
                                 bucket_path: Some(bucket_path.to_string_lossy().to_string()),
                                 manifest_count: None,
+                                commits_pulled: 0,
+                                changed: false,
                             }),
                         }
                     } else {
@@ -465,6 +565,8 @@ fn update_bucket_sync(
                             bucket_name: bucket_name.to_string(),
                             bucket_path: Some(bucket_path.to_string_lossy().to_string()),
                             manifest_count: None,
+                            commits_pulled: 0,
+                            changed: false,
                         })
                     }
                 }
@@ -477,6 +579,8 @@ fn update_bucket_sync(
                     bucket_name: bucket_name.to_string(),
                     bucket_path: Some(bucket_path.to_string_lossy().to_string()),
                     manifest_count: None,
+                    commits_pulled: 0,
+                    changed: false,
                 }),
             }
         }
@@ -489,14 +593,33 @@ fn update_bucket_sync(
             bucket_name: bucket_name.to_string(),
             bucket_path: Some(bucket_path.to_string_lossy().to_string()),
             manifest_count: None,
+            commits_pulled: 0,
+            changed: false,
         }),
     }
 }
 
+/// Counts the commits reachable from `remote` but not from `local`.
+fn count_new_commits(repo: &Repository, local: git2::Oid, remote: git2::Oid) -> u32 {
+    let mut revwalk = match repo.revwalk() {
+        Ok(revwalk) => revwalk,
+        Err(_) => return 0,
+    };
+    if revwalk.push(remote).is_err() || revwalk.hide(local).is_err() {
+        return 0;
+    }
+    revwalk.filter(|oid| oid.is_ok()).count() as u32
+}
+
 /// Command to update all buckets sequentially.
 /// Returns a list of per-bucket results. Non-fatal errors are captured in each result.
+///
+/// Buckets that fail with a network error are retried with exponential backoff
+/// (`buckets.updateRetries` extra attempts, 2 by default) before being reported as failed.
 #[command]
-pub async fn update_all_buckets() -> Result<Vec<BucketInstallResult>, String> {
+pub async fn update_all_buckets<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<Vec<BucketInstallResult>, String> {
     log::info!("Updating all buckets (auto-update task)");
     
     // Pre-fetch and cache the scoop root to avoid repeated path detection
@@ -515,6 +638,14 @@ pub async fn update_all_buckets() -> Result<Vec<BucketInstallResult>, String> {
         return Ok(vec![]);
     }
 
+    let retries =
+        crate::commands::settings::get_config_value(app.clone(), UPDATE_RETRIES_KEY.to_string())
+            .ok()
+            .flatten()
+            .and_then(|v| v.as_u64())
+            .unwrap_or(DEFAULT_UPDATE_RETRIES)
+            .min(MAX_UPDATE_RETRIES) as u32;
+
     let mut results = Vec::new();
 
     let entries = match fs::read_dir(&buckets_dir) {
@@ -528,34 +659,180 @@ pub async fn update_all_buckets() -> Result<Vec<BucketInstallResult>, String> {
             continue;
         }
         if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
-            let name_clone = name.to_string();
-            let path_clone = path.clone();
-            match tokio::task::spawn_blocking(move || update_bucket_sync(&name_clone, &path_clone)).await {
-                Ok(Ok(res)) => results.push(res),
-                Ok(Err(e)) => results.push(BucketInstallResult {
+            results.push(update_bucket_with_retries(name, &path, retries).await);
+        }
+    }
+
+    log::info!("Completed updating {} buckets", results.len());
+
+    if let Err(e) = record_bucket_updates(app, &results) {
+        log::warn!("Failed to record bucket update timestamps: {}", e);
+    }
+    
+    // Clear the scoop root cache after batch update to allow for fresh detection next time
+    crate::utils::clear_scoop_root_cache();
+    
+    Ok(results)
+}
+
+/// Stores the time and pulled commit count of every successful update under
+/// `buckets.lastUpdate.<name>`, so buckets can be tracked independently.
+fn record_bucket_updates<R: Runtime>(
+    app: AppHandle<R>,
+    results: &[BucketInstallResult],
+) -> Result<(), String> {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let values: Vec<_> = results
+        .iter()
+        .filter(|r| r.success)
+        .map(|r| {
+            (
+                format!("{}{}", BUCKET_LAST_UPDATE_PREFIX, r.bucket_name),
+                serde_json::json!({ "timestamp": now, "commitsPulled": r.commits_pulled }),
+            )
+        })
+        .collect();
+    if values.is_empty() {
+        return Ok(());
+    }
+    crate::commands::settings::set_config_values(app, values)
+}
+
+/// Delay before retrying after the given (1-based) failed attempt, doubling each time
+/// up to `MAX_RETRY_DELAY_SECS`.
+fn retry_delay_secs(attempt: u32) -> u64 {
+    1u64.checked_shl(attempt.saturating_sub(1))
+        .map_or(MAX_RETRY_DELAY_SECS, |factor| {
+            RETRY_BASE_DELAY_SECS.saturating_mul(factor)
+        })
+        .min(MAX_RETRY_DELAY_SECS)
+}
+
+/// Updates a single bucket, retrying network failures up to `retries` times.
+///
+/// The attempt count is appended to the result message when more than one attempt was made.
+async fn update_bucket_with_retries(name: &str, path: &Path, retries: u32) -> BucketInstallResult {
+    let mut attempt = 1;
+    loop {
+        let name_clone = name.to_string();
+        let path_clone = path.to_path_buf();
+        let mut result =
+            match tokio::task::spawn_blocking(move || update_bucket_sync(&name_clone, &path_clone))
+                .await
+            {
+                Ok(Ok(res)) => res,
+                Ok(Err(e)) => BucketInstallResult {
                     success: false,
                     message: e,
                     bucket_name: name.to_string(),
                     bucket_path: Some(path.to_string_lossy().to_string()),
                     manifest_count: None,
-                }),
-                Err(e) => results.push(BucketInstallResult {
+                    commits_pulled: 0,
+                    changed: false,
+                },
+                Err(e) => BucketInstallResult {
                     success: false,
                     message: format!("Task failed: {}", e),
                     bucket_name: name.to_string(),
                     bucket_path: Some(path.to_string_lossy().to_string()),
                     manifest_count: None,
-                }),
-            }
+                    commits_pulled: 0,
+                    changed: false,
+                },
+            };
+
+        if !result.success && attempt <= retries && is_network_error(&result.message) {
+            let delay = retry_delay_secs(attempt);
+            log::warn!(
+                "Network error updating bucket '{}' (attempt {}), retrying in {}s: {}",
+                name,
+                attempt,
+                delay,
+                result.message
+            );
+            tokio::time::sleep(std::time::Duration::from_secs(delay)).await;
+            attempt += 1;
+            continue;
+        }
+
+        if attempt > 1 {
+            result.message = format!("{} (after {} attempts)", result.message, attempt);
         }
+        return result;
     }
+}
 
-    log::info!("Completed updating {} buckets", results.len());
-    
-    // Clear the scoop root cache after batch update to allow for fresh detection next time
-    crate::utils::clear_scoop_root_cache();
-    
-    Ok(results)
+/// Returns true if a bucket update failure message looks like a transient network error
+/// rather than a problem with the repository itself.
+fn is_network_error(message: &str) -> bool {
+    let message = message.to_lowercase();
+    NETWORK_ERROR_PATTERNS
+        .iter()
+        .any(|pattern| message.contains(pattern))
+}
+
+// Command to manually update all buckets, refusing to run alongside another bucket update
+#[command]
+pub async fn update_buckets(
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<Vec<BucketInstallResult>, String> {
+    let _guard = state
+        .bucket_update_lock
+        .try_lock()
+        .map_err(|_| UPDATE_IN_PROGRESS_MSG.to_string())?;
+
+    update_all_buckets(app).await
+}
+
+/// Last recorded update of a single bucket.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BucketStatus {
+    pub name: String,
+    /// Unix timestamp of the last successful update, if one was recorded.
+    pub last_update: Option<u64>,
+    /// Number of commits pulled by that update.
+    pub commits_pulled: Option<u32>,
+}
+
+/// Lists every local bucket with its last recorded update.
+#[command]
+pub fn get_bucket_status(app: AppHandle) -> Result<Vec<BucketStatus>, String> {
+    let buckets_dir = get_buckets_dir()?;
+    if !buckets_dir.is_dir() {
+        return Ok(vec![]);
+    }
+
+    let entries = fs::read_dir(&buckets_dir)
+        .map_err(|e| format!("Failed to read buckets directory: {}", e))?;
+    let mut statuses = Vec::new();
+    for entry in entries.flatten() {
+        if !entry.path().is_dir() {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().to_string();
+        let recorded = crate::commands::settings::get_config_value(
+            app.clone(),
+            format!("{}{}", BUCKET_LAST_UPDATE_PREFIX, name),
+        )?;
+        let field = |key: &str| {
+            recorded
+                .as_ref()
+                .and_then(|v| v.get(key))
+                .and_then(|v| v.as_u64())
+        };
+        statuses.push(BucketStatus {
+            last_update: field("timestamp"),
+            commits_pulled: field("commitsPulled").map(|c| c as u32),
+            name,
+        });
+    }
+    statuses.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(statuses)
 }
 
 // Command to remove a bucket
@@ -572,6 +849,8 @@ pub async fn remove_bucket(bucket_name: String) -> Result<BucketInstallResult, S
             bucket_name,
             bucket_path: None,
             manifest_count: None,
+            commits_pulled: 0,
+            changed: false,
         });
     }
 
@@ -587,6 +866,8 @@ pub async fn remove_bucket(bucket_name: String) -> Result<BucketInstallResult, S
                 bucket_name,
                 bucket_path: None,
                 manifest_count: None,
+                commits_pulled: 0,
+                changed: false,
             })
         }
         Err(e) => {
@@ -597,7 +878,89 @@ pub async fn remove_bucket(bucket_name: String) -> Result<BucketInstallResult, S
                 bucket_name,
                 bucket_path: Some(bucket_path.to_string_lossy().to_string()),
                 manifest_count: None,
+                commits_pulled: 0,
+                changed: false,
             })
         }
     }
 }
+
+// Reject bucket names that could escape the buckets directory or break the command line
+fn validate_bucket_name(name: &str) -> Result<(), String> {
+    let is_valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        && name != "."
+        && name != "..";
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(format!("Invalid bucket name: '{}'", name))
+    }
+}
+
+// Read the names of scoop's known buckets from its buckets.json
+fn known_bucket_names() -> Vec<String> {
+    let buckets_json = utils::get_scoop_root_fallback()
+        .join("apps")
+        .join("scoop")
+        .join("current")
+        .join("buckets.json");
+
+    fs::read_to_string(&buckets_json)
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(&content).ok())
+        .map(|map| map.keys().cloned().collect())
+        .unwrap_or_else(|| KNOWN_BUCKETS_FALLBACK.iter().map(|s| s.to_string()).collect())
+}
+
+// Command to add a bucket through `scoop bucket add`, streaming output to the UI
+#[command]
+pub async fn scoop_add_bucket(
+    window: Window,
+    name: String,
+    url: Option<String>,
+) -> Result<(), String> {
+    validate_bucket_name(&name)?;
+
+    let url = match url.filter(|u| !u.trim().is_empty()) {
+        Some(url) => {
+            let normalized = utils::validate_and_normalize_url(url.trim())?;
+            if normalized.chars().any(|c| c.is_whitespace() || matches!(c, '\'' | '"' | '`' | '$' | ';')) {
+                return Err(format!("Invalid bucket URL: '{}'", normalized));
+            }
+            Some(normalized)
+        }
+        None => {
+            let known = known_bucket_names();
+            if !known.iter().any(|k| k.eq_ignore_ascii_case(&name)) {
+                return Err(format!(
+                    "'{}' is not a known bucket. Provide a repository URL to add it. Known buckets: {}",
+                    name,
+                    known.join(", ")
+                ));
+            }
+            None
+        }
+    };
+
+    execute_package_operation(window, ScoopOp::BucketAdd, &name, url.as_deref()).await?;
+    invalidate_manifest_cache().await;
+    Ok(())
+}
+
+// Command to remove a bucket through `scoop bucket rm`, streaming output to the UI
+#[command]
+pub async fn scoop_remove_bucket(window: Window, name: String) -> Result<(), String> {
+    validate_bucket_name(&name)?;
+
+    if !bucket_exists(&name)? {
+        return Err(format!("Bucket '{}' does not exist", name));
+    }
+
+    execute_package_operation(window, ScoopOp::BucketRemove, &name, None).await?;
+    invalidate_manifest_cache().await;
+    Ok(())
+}
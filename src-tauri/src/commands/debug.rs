@@ -1,9 +1,13 @@
 //! Commands for retrieving diagnostic information about the application.
-use crate::state::AppState;
+use crate::error::AppError;
+use crate::state::{AppState, StartupPhase};
 use chrono::Local;
+use serde::{Deserialize, Serialize};
 use std::fs;
+use std::io::{Read, Seek, SeekFrom};
 use std::path::PathBuf;
-use tauri::State;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tauri::{AppHandle, Emitter, Manager, State, Window};
 
 // Note: Retry logic constants are defined locally in functions as needed
 
@@ -17,6 +21,7 @@ const BACKEND_STORE_FILE: &str = "core.json";
 const VERSION_FILE: &str = "version.txt";
 const FACTORY_RESET_MARKER: &str = ".factory_reset";
 const WEBVIEW_CLEANUP_MARKER: &str = ".cleanup_webview_on_startup";
+const LEGACY_MIGRATION_MARKER: &str = ".legacy_data_migrated";
 
 // Legacy store file names (for cleanup)
 const LEGACY_SETTINGS_FILE: &str = "settings.dat";
@@ -31,6 +36,18 @@ const BACKUP_EXT: &str = ".bak";
 // WebView locked patterns
 const WEBVIEW_LOCKED_PATTERNS: &[&str] = &["LOCK", "LOG", "MANIFEST-", ".log"];
 
+// Event emitted for each new line appended to the current log file
+const EVENT_LOG_LINE: &str = "log-line";
+// Event emitted once factory_reset has run all of its steps
+const EVENT_FACTORY_RESET_COMPLETE: &str = "factory-reset-complete";
+const LOG_TAIL_POLL_MS: u64 = 500;
+
+// Incremented on every start/stop so that stale tail tasks exit on their own
+static LOG_TAIL_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+// WebView cache directories that can be removed without losing any user state
+const WEBVIEW_SAFE_CACHE_DIRS: &[&str] = &["GPUCache", "Code Cache"];
+
 // WebView locked directories
 const WEBVIEW_LOCKED_DIRS: &[&str] = &[
     "shared_proto_db",
@@ -41,71 +58,481 @@ const WEBVIEW_LOCKED_DIRS: &[&str] = &[
     "Code Cache",
 ];
 
+/// Resolves the Tauri app data directory (`%APPDATA%\com.rscoop.app` on Windows).
+///
+/// Every marker and store path is built from this, so that all commands fail with the
+/// same error instead of silently doing nothing when the directory cannot be determined.
+fn resolve_app_data_dir() -> Result<PathBuf, String> {
+    dirs::data_dir()
+        .map(|d| d.join(TAURI_APP_ID))
+        .ok_or_else(|| "Could not determine the app data directory".to_string())
+}
+
+/// Resolves the directory holding the app's data: the Tauri app data directory, or the
+/// legacy `rscoop` directory for backward compatibility while the former does not exist.
+fn resolve_existing_data_dir() -> Result<PathBuf, String> {
+    let app_data_dir = resolve_app_data_dir()?;
+    if app_data_dir.exists() {
+        return Ok(app_data_dir);
+    }
+
+    dirs::data_local_dir()
+        .map(|d| d.join(OLD_APP_DIR))
+        .ok_or_else(|| "Could not determine the legacy data directory".to_string())
+}
+
 /// Gets the application data directory
 #[tauri::command]
-pub fn get_app_data_dir() -> Result<String, String> {
-    // First try to get the Tauri app data directory
-    if let Some(app_data_dir) = dirs::data_dir() {
-        let app_data_dir = app_data_dir.join(TAURI_APP_ID);
-        if app_data_dir.exists() {
-            return Ok(app_data_dir.to_string_lossy().to_string());
+pub fn get_app_data_dir() -> Result<String, AppError> {
+    let data_dir = resolve_existing_data_dir().map_err(AppError::Io)?;
+    Ok(data_dir.to_string_lossy().to_string())
+}
+
+/// Outcome of `migrate_legacy_data`, as paths relative to the legacy data directory.
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationReport {
+    pub migrated: Vec<String>,
+    /// Files left in place because the new directory already has a newer copy.
+    pub skipped: Vec<String>,
+    /// Files that could not be moved.
+    pub failed: Vec<String>,
+    /// True if the migration had already run and nothing was done.
+    pub already_done: bool,
+}
+
+/// Moves logs from the legacy `rscoop` directory into the Tauri app data directory,
+/// without overwriting newer files there.
+///
+/// The legacy `settings.dat` and `signals.dat` are left in place: nothing reads them
+/// from the new directory, and the cleanup commands still remove them from the old one.
+///
+/// Runs only once: a marker in the new directory records that the migration happened.
+#[tauri::command]
+pub fn migrate_legacy_data() -> Result<MigrationReport, AppError> {
+    let new_dir = resolve_app_data_dir().map_err(AppError::Io)?;
+    let old_dir = dirs::data_local_dir()
+        .map(|d| d.join(OLD_APP_DIR))
+        .ok_or_else(|| AppError::Io("Could not determine legacy data directory".to_string()))?;
+
+    let marker = new_dir.join(LEGACY_MIGRATION_MARKER);
+    if marker.exists() {
+        return Ok(MigrationReport {
+            already_done: true,
+            ..Default::default()
+        });
+    }
+
+    let mut report = MigrationReport::default();
+    if old_dir.is_dir() {
+        log::info!(
+            "Migrating legacy data from {} to {}",
+            old_dir.display(),
+            new_dir.display()
+        );
+
+        let files: Vec<PathBuf> = fs::read_dir(old_dir.join("logs"))
+            .map(|entries| {
+                entries
+                    .flatten()
+                    .filter(|e| e.path().is_file())
+                    .map(|e| PathBuf::from("logs").join(e.file_name()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        for relative in files {
+            let source = old_dir.join(&relative);
+            if !source.is_file() {
+                continue;
+            }
+            let name = relative.to_string_lossy().to_string();
+            let target = new_dir.join(&relative);
+
+            if is_same_or_newer(&target, &source) {
+                log::info!("Skipping legacy {}: newer copy already present", name);
+                report.skipped.push(name);
+                continue;
+            }
+
+            match move_file(&source, &target) {
+                Ok(()) => report.migrated.push(name),
+                Err(e) => {
+                    log::warn!("Failed to migrate legacy {}: {}", name, e);
+                    report.failed.push(name);
+                }
+            }
         }
     }
 
-    // Fallback to the old rscoop directory for backward compatibility
-    let data_dir = dirs::data_local_dir()
-        .and_then(|d| Some(d.join(OLD_APP_DIR)))
-        .ok_or("Could not determine data directory")?;
+    // Leave the marker unset on failures so the next start retries the remaining files
+    if report.failed.is_empty() {
+        fs::create_dir_all(&new_dir)?;
+        fs::write(&marker, Local::now().to_rfc3339())?;
+    }
 
-    Ok(data_dir.to_string_lossy().to_string())
+    log::info!(
+        "Legacy data migration: {} migrated, {} skipped, {} failed",
+        report.migrated.len(),
+        report.skipped.len(),
+        report.failed.len()
+    );
+    Ok(report)
+}
+
+/// Returns true if `target` exists and was modified no earlier than `source`.
+fn is_same_or_newer(target: &std::path::Path, source: &std::path::Path) -> bool {
+    let modified = |path: &std::path::Path| fs::metadata(path).and_then(|m| m.modified()).ok();
+    match (modified(target), modified(source)) {
+        (Some(target), Some(source)) => target >= source,
+        (Some(_), None) => true,
+        (None, _) => false,
+    }
+}
+
+/// Moves a file, falling back to copy and delete when a rename is not possible
+/// (e.g. the directories are on different volumes).
+fn move_file(source: &std::path::Path, target: &std::path::Path) -> std::io::Result<()> {
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    if fs::rename(source, target).is_ok() {
+        return Ok(());
+    }
+    fs::copy(source, target)?;
+    fs::remove_file(source)
+}
+
+/// Returns the duration of each phase of the current startup sequence.
+///
+/// Before the current startup has recorded anything, the timings persisted by the
+/// last completed startup are returned instead.
+#[tauri::command]
+pub fn get_startup_timings(
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<Vec<StartupPhase>, AppError> {
+    let timings = state.startup_timings();
+    if !timings.is_empty() {
+        return Ok(timings);
+    }
+
+    Ok(crate::commands::settings::get_config_value(
+        app,
+        crate::cold_start::LAST_STARTUP_TIMINGS_KEY.to_string(),
+    )
+    .map_err(AppError::OperationFailed)?
+    .and_then(|v| serde_json::from_value(v).ok())
+    .unwrap_or_default())
+}
+
+/// Opens one of the app's directories in the file manager.
+///
+/// `kind` is one of `"logs"`, `"data"`, `"scoop"` or `"cache"`.
+#[tauri::command]
+pub fn reveal_in_explorer(state: State<'_, AppState>, kind: String) -> Result<(), AppError> {
+    let dir = match kind.as_str() {
+        "logs" => get_log_dir().map_err(AppError::Io)?,
+        "data" => PathBuf::from(get_app_data_dir()?),
+        "scoop" => state.scoop_path(),
+        "cache" => state.scoop_path().join("cache"),
+        other => {
+            return Err(AppError::InvalidInput(format!(
+                "Unknown directory kind: '{}'",
+                other
+            )))
+        }
+    };
+
+    if !dir.is_dir() {
+        return Err(AppError::Io(format!(
+            "The {} directory does not exist yet: {}",
+            kind,
+            dir.display()
+        )));
+    }
+
+    log::info!("Revealing {} directory: {}", kind, dir.display());
+    crate::commands::info::open_in_file_manager(&dir).map_err(AppError::OperationFailed)
 }
 
 /// Gets the log directory
 #[tauri::command]
-pub fn get_log_dir_cmd() -> Result<String, String> {
-    let log_dir = get_log_dir().ok_or("Could not determine log directory")?;
+pub fn get_log_dir_cmd() -> Result<String, AppError> {
+    let log_dir = get_log_dir().map_err(AppError::Io)?;
     Ok(log_dir.to_string_lossy().to_string())
 }
 
+/// Settings keys for log retention
+pub const LOG_RETENTION_MODE_KEY: &str = "logs.retentionMode";
+pub const LOG_RETENTION_VALUE_KEY: &str = "logs.retentionValue";
+const DEFAULT_LOG_RETENTION_DAYS: u32 = 7;
+/// Subdirectory of the log directory holding one log file per streamed operation
+const OPERATION_LOGS_DIR: &str = "operations";
+
+/// How old log files are pruned
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LogRetentionMode {
+    /// Remove log files older than `value` days
+    Days,
+    /// Keep only the `value` most recent log files
+    Count,
+}
+
+/// Log retention policy stored in settings
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct LogRetention {
+    pub mode: LogRetentionMode,
+    pub value: u32,
+}
+
+impl Default for LogRetention {
+    fn default() -> Self {
+        Self {
+            mode: LogRetentionMode::Days,
+            value: DEFAULT_LOG_RETENTION_DAYS,
+        }
+    }
+}
+
+/// Reads the log retention policy from settings, falling back to 7 days
+pub fn read_log_retention<R: tauri::Runtime>(app: &AppHandle<R>) -> LogRetention {
+    let read = |key: &str| {
+        crate::commands::settings::get_config_value(app.clone(), key.to_string())
+            .ok()
+            .flatten()
+    };
+
+    let mode = read(LOG_RETENTION_MODE_KEY)
+        .and_then(|v| serde_json::from_value::<LogRetentionMode>(v).ok())
+        .unwrap_or(LogRetentionMode::Days);
+    let value = read(LOG_RETENTION_VALUE_KEY)
+        .and_then(|v| v.as_u64())
+        .map(|v| v as u32)
+        .unwrap_or(DEFAULT_LOG_RETENTION_DAYS);
+
+    LogRetention { mode, value }
+}
+
+/// Gets the log retention policy
+#[tauri::command]
+pub fn get_log_retention(app: AppHandle) -> Result<LogRetention, AppError> {
+    Ok(read_log_retention(&app))
+}
+
+/// Sets the log retention policy
+#[tauri::command]
+pub fn set_log_retention(
+    app: AppHandle,
+    mode: LogRetentionMode,
+    value: u32,
+) -> Result<(), AppError> {
+    if value == 0 {
+        return Err(AppError::InvalidInput(
+            "Log retention value must be at least 1".to_string(),
+        ));
+    }
+    log::info!("Setting log retention to {:?} = {}", mode, value);
+
+    let mode_value =
+        serde_json::to_value(mode).map_err(|e| AppError::InvalidInput(e.to_string()))?;
+    crate::commands::settings::set_config_value(
+        app.clone(),
+        LOG_RETENTION_MODE_KEY.to_string(),
+        mode_value,
+    )
+    .map_err(AppError::OperationFailed)?;
+    crate::commands::settings::set_config_value(
+        app,
+        LOG_RETENTION_VALUE_KEY.to_string(),
+        serde_json::json!(value),
+    )
+    .map_err(AppError::OperationFailed)?;
+    Ok(())
+}
+
 /// Gets the log retention days setting
 #[tauri::command]
-pub fn get_log_retention_days() -> Result<i32, String> {
-    Ok(7)
+pub fn get_log_retention_days(app: AppHandle) -> Result<i32, AppError> {
+    let retention = read_log_retention(&app);
+    match retention.mode {
+        LogRetentionMode::Days => Ok(retention.value as i32),
+        LogRetentionMode::Count => Ok(DEFAULT_LOG_RETENTION_DAYS as i32),
+    }
 }
 
 /// Sets the log retention days setting
 #[tauri::command]
-pub fn set_log_retention_days(days: i32) -> Result<(), String> {
-    log::info!("Setting log retention to {} days", days);
-    Ok(())
+pub fn set_log_retention_days(app: AppHandle, days: i32) -> Result<(), AppError> {
+    let days = u32::try_from(days)
+        .map_err(|_| AppError::InvalidInput(format!("Invalid retention days: {}", days)))?;
+    set_log_retention(app, LogRetentionMode::Days, days)
+}
+
+/// Removes log files that fall outside the retention policy. The active log file is kept.
+/// Returns the number of removed files.
+pub fn prune_old_logs(
+    log_dir: &std::path::Path,
+    retention: &LogRetention,
+) -> Result<usize, String> {
+    let now = std::time::SystemTime::now();
+    let max_age = std::time::Duration::from_secs(u64::from(retention.value) * 24 * 60 * 60);
+
+    let active_log = current_log_file_path()?;
+
+    let mut removed = 0;
+    for (index, (modified, path)) in log_files_newest_first(log_dir).into_iter().enumerate() {
+        if path.file_name() == active_log.file_name() {
+            continue;
+        }
+
+        let expired = match retention.mode {
+            LogRetentionMode::Count => index >= retention.value as usize,
+            LogRetentionMode::Days => now
+                .duration_since(modified)
+                .map(|age| age > max_age)
+                .unwrap_or(false),
+        };
+
+        if expired {
+            match fs::remove_file(&path) {
+                Ok(_) => removed += 1,
+                Err(e) => log::debug!("Failed to remove log file {}: {}", path.display(), e),
+            }
+        }
+    }
+
+    if removed > 0 {
+        log::info!(
+            "Pruned {} old log files ({:?} = {})",
+            removed,
+            retention.mode,
+            retention.value
+        );
+    }
+    Ok(removed)
+}
+
+/// Prunes the app's log directory, including per-operation logs, using the retention
+/// policy from settings
+pub fn prune_old_logs_from_settings<R: tauri::Runtime>(app: &AppHandle<R>) {
+    let retention = read_log_retention(app);
+    let pruned = get_log_dir().and_then(|log_dir| {
+        prune_old_logs(&log_dir, &retention)?;
+        prune_old_logs(&log_dir.join(OPERATION_LOGS_DIR), &retention)
+    });
+    if let Err(e) = pruned {
+        log::warn!("Failed to prune old logs: {}", e);
+    }
+}
+
+/// Returns the log file path for an operation id.
+///
+/// Characters outside `[A-Za-z0-9._@-]` are replaced so the id can never escape the directory.
+pub(crate) fn operation_log_path(operation_id: &str) -> Result<PathBuf, AppError> {
+    let file_stem: String = operation_id
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '@' | '-') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if file_stem.is_empty() {
+        return Err(AppError::InvalidInput(format!(
+            "Invalid operation id: '{}'",
+            operation_id
+        )));
+    }
+    let log_dir = get_log_dir().map_err(AppError::Io)?;
+    Ok(log_dir
+        .join(OPERATION_LOGS_DIR)
+        .join(format!("{}.log", file_stem)))
+}
+
+/// Reads the combined output recorded for an operation.
+#[tauri::command]
+pub fn read_operation_log(operation_id: String) -> Result<String, AppError> {
+    let path = operation_log_path(&operation_id)?;
+    if !path.is_file() {
+        return Err(AppError::Io(format!(
+            "No log found for operation '{}'",
+            operation_id
+        )));
+    }
+    fs::read_to_string(&path)
+        .map_err(|e| AppError::Io(format!("Failed to read operation log: {}", e)))
+}
+
+/// Lists `*.log` files in `log_dir` with their modification time, newest first
+fn log_files_newest_first(log_dir: &std::path::Path) -> Vec<(std::time::SystemTime, PathBuf)> {
+    let mut files: Vec<(std::time::SystemTime, PathBuf)> = match fs::read_dir(log_dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.is_file() && path.extension().and_then(|e| e.to_str()) == Some("log")
+            })
+            .map(|path| {
+                let modified = fs::metadata(&path)
+                    .and_then(|m| m.modified())
+                    .unwrap_or(std::time::SystemTime::UNIX_EPOCH);
+                (modified, path)
+            })
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+
+    // Sort by modification time, newest first
+    files.sort_by(|a, b| b.0.cmp(&a.0));
+    files
 }
 
 /// Safely removes a file with retry logic
 fn safe_remove_file(file_path: &std::path::Path) -> bool {
-    const MAX_RETRIES: u32 = 3;
-    const RETRY_DELAY_MS: u64 = 100;
-    
     // Skip WebView2 database files completely - they're heavily locked
     if is_webview_locked_file(file_path) {
         log::info!("Skipping WebView2 locked file: {}", file_path.display());
         return false;
     }
-    
+
+    remove_file_with_retries(file_path, true)
+}
+
+/// Removes a file with retry logic, without the WebView2 lock check.
+///
+/// Per-attempt debug lines are only written when `verbose` is set; a final failure is
+/// always logged as a warning.
+fn remove_file_with_retries(file_path: &std::path::Path, verbose: bool) -> bool {
+    const MAX_RETRIES: u32 = 3;
+    const RETRY_DELAY_MS: u64 = 100;
+
     for attempt in 1..=MAX_RETRIES {
         match fs::remove_file(file_path) {
             Ok(_) => {
-                log::debug!("Successfully removed file: {}", file_path.display());
+                if verbose {
+                    log::debug!("Successfully removed file: {}", file_path.display());
+                }
                 return true;
             }
             Err(e) => {
                 if attempt == MAX_RETRIES {
-                    log::debug!("Failed to remove file after {} attempts: {} - {}", 
-                               MAX_RETRIES, file_path.display(), e);
+                    log::warn!(
+                        "Failed to remove file after {} attempts: {} - {}",
+                        MAX_RETRIES,
+                        file_path.display(),
+                        e
+                    );
                     return false;
                 }
                 
-                log::debug!("Attempt {} failed to remove file: {} - {}", 
-                           attempt, file_path.display(), e);
+                if verbose {
+                    log::debug!("Attempt {} failed to remove file: {} - {}", 
+                               attempt, file_path.display(), e);
+                }
                 
                 // Wait before retrying
                 std::thread::sleep(std::time::Duration::from_millis(RETRY_DELAY_MS));
@@ -116,31 +543,47 @@ fn safe_remove_file(file_path: &std::path::Path) -> bool {
 }
 
 /// Safely removes a directory with retry logic
-fn safe_remove_dir(dir_path: &std::path::Path) -> bool {
-    const MAX_RETRIES: u32 = 3;
-    const RETRY_DELAY_MS: u64 = 200;
-    
+pub(crate) fn safe_remove_dir(dir_path: &std::path::Path) -> bool {
     // Skip WebView2 locked directories
     if is_webview_locked_dir(dir_path) {
         log::info!("Skipping WebView2 locked directory: {}", dir_path.display());
         return false;
     }
-    
+
+    remove_dir_with_retries(dir_path, true)
+}
+
+/// Removes a directory with retry logic, without the WebView2 lock check
+///
+/// Per-attempt debug lines are only written when `verbose` is set; a final failure is
+/// always logged as a warning.
+fn remove_dir_with_retries(dir_path: &std::path::Path, verbose: bool) -> bool {
+    const MAX_RETRIES: u32 = 3;
+    const RETRY_DELAY_MS: u64 = 200;
+
     for attempt in 1..=MAX_RETRIES {
         match fs::remove_dir_all(dir_path) {
             Ok(_) => {
-                log::debug!("Successfully removed directory: {}", dir_path.display());
+                if verbose {
+                    log::debug!("Successfully removed directory: {}", dir_path.display());
+                }
                 return true;
             }
             Err(e) => {
                 if attempt == MAX_RETRIES {
-                    log::debug!("Failed to remove directory after {} attempts: {} - {}", 
-                               MAX_RETRIES, dir_path.display(), e);
+                    log::warn!(
+                        "Failed to remove directory after {} attempts: {} - {}",
+                        MAX_RETRIES,
+                        dir_path.display(),
+                        e
+                    );
                     return false;
                 }
                 
-                log::debug!("Attempt {} failed to remove directory: {} - {}", 
-                           attempt, dir_path.display(), e);
+                if verbose {
+                    log::debug!("Attempt {} failed to remove directory: {} - {}", 
+                               attempt, dir_path.display(), e);
+                }
                 
                 // Wait before retrying
                 std::thread::sleep(std::time::Duration::from_millis(RETRY_DELAY_MS));
@@ -168,51 +611,274 @@ fn is_webview_locked_dir(dir_path: &std::path::Path) -> bool {
     }
 }
 
-/// Clears all application data and cache
-#[tauri::command]
-pub fn clear_application_data() -> Result<(), String> {
-    // First try to get the Tauri app data directory
-    let data_dir = if let Some(app_data_dir) = dirs::data_dir() {
-        let app_data_dir = app_data_dir.join("com.rscoop.app");
-        if app_data_dir.exists() {
-            app_data_dir
+/// Number of worker threads used when clearing large directories
+const CLEAR_DIR_THREADS: usize = 8;
+
+/// Files the running application keeps open for its whole lifetime
+const APP_LOCKED_FILES: &[&str] = &["rscoop.log"];
+
+/// Checks if a file is held open by the running application (e.g. the active log file)
+///
+/// Only the known lock files and `.log` files inside the app's `logs` directory match;
+/// other files are not skipped just because their name mentions the app.
+fn is_file_locked_by_current_process(file_path: &std::path::Path) -> bool {
+    let file_name = match file_path.file_name().and_then(|n| n.to_str()) {
+        Some(name) => name,
+        None => return false,
+    };
+
+    if APP_LOCKED_FILES.contains(&file_name) {
+        return true;
+    }
+
+    let in_logs_dir = file_path
+        .parent()
+        .and_then(|p| p.file_name())
+        .map_or(false, |dir| dir == "logs");
+    in_logs_dir && file_name.ends_with(".log")
+}
+
+/// Per-category counts collected while clearing a directory.
+#[derive(Debug, Default)]
+struct ClearSummary {
+    /// Entries that were removed.
+    removed: usize,
+    /// Files held open by the running application.
+    locked: usize,
+    /// WebView2 files and directories that were left alone.
+    webview_skipped: usize,
+    /// Entries that could not be removed.
+    failed: Vec<PathBuf>,
+}
+
+impl ClearSummary {
+    fn merge(&mut self, other: ClearSummary) {
+        self.removed += other.removed;
+        self.locked += other.locked;
+        self.webview_skipped += other.webview_skipped;
+        self.failed.extend(other.failed);
+    }
+}
+
+/// Removes the contents of a directory in parallel, skipping files that are locked.
+///
+/// Deletion runs on one bounded rayon pool since the data directory can contain
+/// thousands of WebView cache files; subdirectories are descended into on the same
+/// pool, so the files of a large cache directory are removed in parallel as well.
+/// Entries are only logged individually when `verbose` is set; otherwise callers log
+/// the returned summary.
+fn clear_regular_directory(dir: &std::path::Path, verbose: bool) -> Result<ClearSummary, String> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(CLEAR_DIR_THREADS)
+        .build()
+        .map_err(|e| format!("Failed to create thread pool: {}", e))?;
+
+    pool.install(|| clear_directory_contents(dir, verbose))
+}
+
+/// Clears the entries of `dir` on the current rayon pool, skipping locked files.
+fn clear_directory_contents(dir: &std::path::Path, verbose: bool) -> Result<ClearSummary, String> {
+    use rayon::prelude::*;
+
+    let entries: Vec<PathBuf> = fs::read_dir(dir)
+        .map_err(|e| format!("Failed to read directory {}: {}", dir.display(), e))?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .collect();
+
+    Ok(entries
+        .par_iter()
+        .map(|path| clear_entry(path, verbose))
+        .reduce(ClearSummary::default, |mut total, summary| {
+            total.merge(summary);
+            total
+        }))
+}
+
+/// Clears one top-level entry of the data directory.
+fn clear_entry(path: &std::path::Path, verbose: bool) -> ClearSummary {
+    let mut summary = ClearSummary::default();
+    if path.is_file() {
+        let locked = is_file_locked_by_current_process(path);
+        if locked || is_webview_locked_file(path) {
+            if verbose {
+                log::debug!("Skipping locked file: {}", path.display());
+            }
+            if locked {
+                summary.locked += 1;
+            } else {
+                summary.webview_skipped += 1;
+            }
+        } else if remove_file_with_retries(path, verbose) {
+            summary.removed += 1;
         } else {
-            dirs::data_local_dir()
-                .and_then(|d| Some(d.join("rscoop")))
-                .ok_or("Could not determine data directory")?
+            summary.failed.push(path.to_path_buf());
         }
-    } else {
-        dirs::data_local_dir()
-            .and_then(|d| Some(d.join("rscoop")))
-            .ok_or("Could not determine data directory")?
+    } else if path.is_dir() {
+        if is_webview_locked_dir(path) {
+            if verbose {
+                log::debug!("Skipping locked directory: {}", path.display());
+            }
+            summary.webview_skipped += 1;
+        } else if path.file_name().and_then(|n| n.to_str()) == Some("logs") {
+            // Keep the directory itself so the active log file stays valid
+            match clear_directory_contents(path, verbose) {
+                Ok(nested) => summary.merge(nested),
+                Err(e) => log::warn!("{}", e),
+            }
+        } else {
+            summary.merge(remove_tree(path, verbose));
+        }
+    }
+    summary
+}
+
+/// Removes a directory and everything below it, deleting the entries of each level in
+/// parallel. Symlinks and junctions are removed without following them.
+fn remove_tree(dir: &std::path::Path, verbose: bool) -> ClearSummary {
+    use rayon::prelude::*;
+
+    let entries: Vec<(PathBuf, bool)> = match fs::read_dir(dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| {
+                let is_real_dir = entry.file_type().is_ok_and(|t| t.is_dir());
+                (entry.path(), is_real_dir)
+            })
+            .collect(),
+        Err(_) => Vec::new(),
     };
-    
-    if data_dir.exists() && data_dir.is_dir() {
-        for entry in fs::read_dir(&data_dir).map_err(|e| e.to_string())? {
-            let entry = entry.map_err(|e| e.to_string())?;
-            let path = entry.path();
-            
-            if path.is_file() {
-                fs::remove_file(&path).map_err(|e| e.to_string())?;
-            } else if path.is_dir() {
-                fs::remove_dir_all(&path).map_err(|e| e.to_string())?;
+
+    let mut summary = entries
+        .par_iter()
+        .map(|(path, is_real_dir)| {
+            if *is_real_dir {
+                return remove_tree(path, verbose);
+            }
+            let mut summary = ClearSummary::default();
+            let removed = if path.is_dir() {
+                remove_dir_with_retries(path, verbose)
+            } else {
+                remove_file_with_retries(path, verbose)
+            };
+            if removed {
+                summary.removed += 1;
+            } else {
+                summary.failed.push(path.clone());
             }
+            summary
+        })
+        .reduce(ClearSummary::default, |mut total, summary| {
+            total.merge(summary);
+            total
+        });
+
+    // A directory with failed entries cannot be removed, so it is not reported again
+    if summary.failed.is_empty() {
+        if remove_dir_with_retries(dir, verbose) {
+            summary.removed += 1;
+        } else {
+            summary.failed.push(dir.to_path_buf());
         }
     }
-    
-    Ok(())
+    summary
+}
+
+/// Clears all application data and cache
+///
+/// A per-category summary and every entry that could not be removed are logged; with
+/// `verbose` set, every skipped or removed entry is logged at debug level as well.
+#[tauri::command]
+pub fn clear_application_data(verbose: Option<bool>) -> Result<(), AppError> {
+    clear_app_data_dir(verbose.unwrap_or(false)).map(|_| ())
+}
+
+/// Clears the application data directory, returning the number of removed entries.
+fn clear_app_data_dir(verbose: bool) -> Result<usize, AppError> {
+    let data_dir = resolve_existing_data_dir().map_err(AppError::Io)?;
+
+    if !data_dir.is_dir() {
+        return Ok(0);
+    }
+
+    let start = std::time::Instant::now();
+    let summary = clear_regular_directory(&data_dir, verbose).map_err(AppError::Io)?;
+    log::info!(
+        "Cleared {} entries from {} in {:.2}s",
+        summary.removed,
+        data_dir.display(),
+        start.elapsed().as_secs_f64()
+    );
+    log::info!("Skipped {} files locked by the app", summary.locked);
+    log::info!("Skipped {} WebView2 entries", summary.webview_skipped);
+    if !summary.failed.is_empty() {
+        log::warn!("Failed to remove {} entries:", summary.failed.len());
+        for path in &summary.failed {
+            log::warn!("  - could not remove: {}", path.display());
+        }
+    }
+
+    Ok(summary.removed)
+}
+
+/// Outcome of each factory reset step, emitted with `factory-reset-complete`.
+#[derive(Serialize, Debug, Clone)]
+pub struct FactoryResetResult {
+    pub data_cleared: bool,
+    pub store_cleared: bool,
+    pub marker_created: bool,
+    pub webview_scheduled: bool,
 }
 
 /// Factory reset - clears all application data and marks for factory reset
+///
+/// Each completed step is streamed as an `operation-output` line, followed by
+/// `operation-finished`. `factory-reset-complete` is emitted with the outcome of each
+/// step so the UI can prompt for a restart.
 #[tauri::command]
-pub fn factory_reset(app: tauri::AppHandle) -> Result<(), String> {
+pub fn factory_reset(window: Window, operation_id: Option<String>) -> Result<(), AppError> {
+    use crate::commands::powershell::{CommandResult, StreamOutput, EVENT_FINISHED, EVENT_OUTPUT};
+
     log::info!("Starting factory reset process");
-    
+    let app = window.app_handle().clone();
+    let emit_line = |line: String, source: &str| {
+        if let Err(e) = window.emit(
+            EVENT_OUTPUT,
+            StreamOutput::new(line, source, operation_id.clone()),
+        ) {
+            log::warn!("Failed to emit factory reset output: {}", e);
+        }
+    };
+
     // Clear all application data
-    clear_application_data()?;
-    
+    let data_result = clear_app_data_dir(false);
+    match &data_result {
+        Ok(removed) => emit_line(
+            format!("Cleared application data: {} entries", removed),
+            "stdout",
+        ),
+        Err(e) => {
+            log::error!("Failed to clear application data: {}", e);
+            emit_line(format!("Failed to clear application data: {}", e), "stderr");
+        }
+    }
+
     // Clear store data and create factory reset marker
-    clear_store_data()?;
+    if let Err(e) = crate::commands::settings::clear_loaded_store(&app) {
+        log::warn!("Failed to clear in-memory store: {}", e);
+    }
+    let store_cleared = remove_store_files();
+    if store_cleared {
+        emit_line("Cleared store data".to_string(), "stdout");
+    } else {
+        emit_line("Failed to clear store data".to_string(), "stderr");
+    }
+    let marker_created = create_factory_reset_marker();
+    if marker_created {
+        emit_line("Created reset marker".to_string(), "stdout");
+    } else {
+        emit_line("Failed to create reset marker".to_string(), "stderr");
+    }
     
     // Reset tray notification setting to show it again on next startup
     let _ = crate::commands::settings::set_config_value(
@@ -222,19 +888,92 @@ pub fn factory_reset(app: tauri::AppHandle) -> Result<(), String> {
     );
     
     // Schedule WebView cleanup for next startup
-    schedule_webview_cleanup()?;
+    let webview_scheduled = match schedule_webview_cleanup() {
+        Ok(()) => {
+            emit_line("Scheduled WebView cleanup".to_string(), "stdout");
+            true
+        }
+        Err(e) => {
+            log::warn!("Failed to schedule WebView cleanup: {}", e);
+            emit_line(
+                format!("Failed to schedule WebView cleanup: {}", e),
+                "stderr",
+            );
+            false
+        }
+    };
     
     // Clear Windows registry data
     #[cfg(windows)]
-    clear_registry_data()?;
+    let registry_result = clear_registry_data();
     
+    let result = FactoryResetResult {
+        data_cleared: data_result.is_ok(),
+        store_cleared,
+        marker_created,
+        webview_scheduled,
+    };
+    if let Err(e) = app.emit(EVENT_FACTORY_RESET_COMPLETE, &result) {
+        log::warn!("Failed to emit factory reset completion event: {}", e);
+    }
+
+    #[cfg(windows)]
+    let outcome = data_result.map(|_| ()).and(registry_result);
+    #[cfg(not(windows))]
+    let outcome = data_result.map(|_| ());
+
+    let finished = CommandResult {
+        success: outcome.is_ok(),
+        message: match &outcome {
+            Ok(()) => "Factory reset completed".to_string(),
+            Err(e) => format!("Factory reset failed: {}", e),
+        },
+        operation_id: operation_id.clone(),
+    };
+    if let Err(e) = window.emit(EVENT_FINISHED, finished) {
+        log::warn!("Failed to emit factory reset finished event: {}", e);
+    }
+
+    outcome?;
     log::info!("Factory reset completed successfully");
     Ok(())
 }
 
+/// Installed packages cache state reported by `get_debug_info`.
+#[derive(Serialize, Debug, Clone)]
+pub struct DebugCacheInfo {
+    pub cached_count: usize,
+    pub fingerprint: Option<String>,
+}
+
+/// Last computed disk usage, reported by `get_debug_info` when available.
+#[derive(Serialize, Debug, Clone)]
+pub struct DebugDiskInfo {
+    pub apps_total_bytes: u64,
+    pub cache_bytes: u64,
+    pub total_bytes: u64,
+}
+
+/// Diagnostic information about the application's state.
+#[derive(Serialize, Debug, Clone)]
+pub struct DebugInfo {
+    /// Time the info was collected, in RFC 3339 format.
+    pub timestamp: String,
+    pub scoop_path: String,
+    pub apps_dir_exists: bool,
+    /// Number of entries in the `apps` directory.
+    pub app_count: usize,
+    /// Whether Scoop itself is installed, i.e. `apps/scoop/current` exists.
+    pub scoop_installed: bool,
+    #[serde(rename = "cache_info")]
+    pub cache: DebugCacheInfo,
+    /// Cached result of `get_disk_usage`; not computed here as walking the apps is slow.
+    pub disk: Option<DebugDiskInfo>,
+}
+
 /// Gets diagnostic information about the application's state.
 #[tauri::command]
-pub async fn get_debug_info(state: State<'_, AppState>) -> Result<serde_json::Value, String> {
+pub async fn get_debug_info(state: State<'_, AppState>) -> Result<DebugInfo, AppError> {
     let scoop_path = state.scoop_path();
     let apps_path = scoop_path.join("apps");
 
@@ -256,100 +995,119 @@ pub async fn get_debug_info(state: State<'_, AppState>) -> Result<serde_json::Va
 
     // Check cache state
     let cache_guard = state.installed_packages.lock().await;
-    let cache_info = if let Some(cache) = cache_guard.as_ref() {
+    let cache = if let Some(cache) = cache_guard.as_ref() {
         log::info!(
             "=== DEBUG INFO === Cache found with {} packages, fingerprint: {}",
             cache.packages.len(),
             cache.fingerprint
         );
-        serde_json::json!({
-            "cached_count": cache.packages.len(),
-            "fingerprint": cache.fingerprint,
-        })
+        DebugCacheInfo {
+            cached_count: cache.packages.len(),
+            fingerprint: Some(cache.fingerprint.clone()),
+        }
     } else {
         log::info!("=== DEBUG INFO === No cache found (None)");
-        serde_json::json!({
-            "cached_count": 0,
-            "fingerprint": null,
-        })
+        DebugCacheInfo {
+            cached_count: 0,
+            fingerprint: None,
+        }
     };
     drop(cache_guard); // Explicitly drop guard
 
-    let debug_result = serde_json::json!({
-        "timestamp": Local::now().to_rfc3339(),
-        "scoop_path": scoop_path.display().to_string(),
-        "apps_dir_exists": apps_dir_exists,
-        "app_count": app_count,
-        "cache_info": cache_info,
-    });
+    let disk = state
+        .disk_usage
+        .lock()
+        .await
+        .as_ref()
+        .map(|cache| DebugDiskInfo {
+            apps_total_bytes: cache.report.apps_total_bytes,
+            cache_bytes: cache.report.cache_bytes,
+            total_bytes: cache.report.total_bytes,
+        });
+
+    let debug_info = DebugInfo {
+        timestamp: Local::now().to_rfc3339(),
+        scoop_path: scoop_path.display().to_string(),
+        apps_dir_exists,
+        app_count,
+        scoop_installed: apps_path.join("scoop").join("current").is_dir(),
+        cache,
+        disk,
+    };
 
     log::info!(
         "=== DEBUG INFO === Returning debug info: cached_count={}, app_count={}",
-        debug_result["cache_info"]["cached_count"],
+        debug_info.cache.cached_count,
         app_count
     );
 
-    Ok(debug_result)
+    Ok(debug_info)
 }
 
 /// Gets the current application logs from the logging system
 #[tauri::command]
-pub fn get_app_logs() -> Result<String, String> {
+pub fn get_app_logs() -> Result<String, AppError> {
     let mut log_info = String::new();
 
     log_info.push_str("=== LOGGING INFORMATION ===\n\n");
 
     log_info.push_str("Location:\n");
-    if let Some(log_dir) = get_log_dir() {
-        log_info.push_str(&format!("  Directory: {}\n", log_dir.display()));
-        
-        if log_dir.exists() {
-            match fs::read_dir(&log_dir) {
-                Ok(entries) => {
-                    let mut log_files: Vec<_> = entries
-                        .filter_map(|entry| entry.ok())
-                        .filter(|entry| entry.path().is_file())
-                        .collect();
-                    
-                    // Sort by modification time, newest first
-                    log_files.sort_by(|a, b| {
-                        let a_time = a.metadata().and_then(|m| m.modified()).unwrap_or(std::time::SystemTime::UNIX_EPOCH);
-                        let b_time = b.metadata().and_then(|m| m.modified()).unwrap_or(std::time::SystemTime::UNIX_EPOCH);
-                        b_time.cmp(&a_time)
-                    });
-                    
-                    log_info.push_str(&format!("  Log files ({} total):\n", log_files.len()));
-                    for (i, entry) in log_files.iter().take(5).enumerate() {
-                        if let Ok(metadata) = entry.metadata() {
-                            if let Ok(modified) = metadata.modified() {
-                                let datetime: chrono::DateTime<Local> = modified.into();
-                                log_info.push_str(&format!(
-                                    "    {}. {} ({})\n",
-                                    i + 1,
-                                    entry.file_name().to_string_lossy(),
-                                    datetime.format("%Y-%m-%d %H:%M:%S")
-                                ));
+    match get_log_dir() {
+        Ok(log_dir) => {
+            log_info.push_str(&format!("  Directory: {}\n", log_dir.display()));
+
+            if log_dir.exists() {
+                match fs::read_dir(&log_dir) {
+                    Ok(entries) => {
+                        let mut log_files: Vec<_> = entries
+                            .filter_map(|entry| entry.ok())
+                            .filter(|entry| entry.path().is_file())
+                            .collect();
+
+                        // Sort by modification time, newest first
+                        log_files.sort_by(|a, b| {
+                            let a_time = a.metadata().and_then(|m| m.modified()).unwrap_or(std::time::SystemTime::UNIX_EPOCH);
+                            let b_time = b.metadata().and_then(|m| m.modified()).unwrap_or(std::time::SystemTime::UNIX_EPOCH);
+                            b_time.cmp(&a_time)
+                        });
+
+                        log_info.push_str(&format!("  Log files ({} total):\n", log_files.len()));
+                        for (i, entry) in log_files.iter().take(5).enumerate() {
+                            if let Ok(metadata) = entry.metadata() {
+                                if let Ok(modified) = metadata.modified() {
+                                    let datetime: chrono::DateTime<Local> = modified.into();
+                                    log_info.push_str(&format!(
+                                        "    {}. {} ({})\n",
+                                        i + 1,
+                                        entry.file_name().to_string_lossy(),
+                                        datetime.format("%Y-%m-%d %H:%M:%S")
+                                    ));
+                                }
                             }
                         }
+                        if log_files.len() > 5 {
+                            log_info.push_str(&format!("    ... and {} more\n", log_files.len() - 5));
+                        }
                     }
-                    if log_files.len() > 5 {
-                        log_info.push_str(&format!("    ... and {} more\n", log_files.len() - 5));
+                    Err(e) => {
+                        log_info.push_str(&format!("  Failed to read directory: {}\n", e));
                     }
                 }
-                Err(e) => {
-                    log_info.push_str(&format!("  Failed to read directory: {}\n", e));
-                }
+            } else {
+                log_info.push_str("  Directory does not exist yet.\n");
+            }
+
+            let log_path = log_dir.join("rscoop.log");
+            if log_path.exists() {
+                log_info.push_str(&format!("  Expected location: {}\n", log_path.display()));
             }
-        } else {
-            log_info.push_str("  Directory does not exist yet.\n");
         }
-        
-        let log_path = log_dir.join("rscoop.log");
-        if log_path.exists() {
-            log_info.push_str(&format!("  Expected location: {}\n", log_path.display()));
+        Err(e) => {
+            log_info.push_str(&format!(
+                "  Could not determine log directory location: {}\n",
+                e
+            ));
         }
-    } else {
-        log_info.push_str("  Could not determine log directory location.\n");
     }
 
     log_info.push_str("\nTo View Logs:\n");
@@ -375,64 +1133,450 @@ pub fn get_app_logs() -> Result<String, String> {
     Ok(log_info)
 }
 
-/// Reads the current application log file
+/// Number of bytes returned from the end of the log when no offset is given
+const DEFAULT_LOG_TAIL_BYTES: u64 = 256 * 1024;
+
+/// A slice of the current log file, as returned by `read_app_log_file`.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LogChunk {
+    pub content: String,
+    /// Byte offset of `content` within the file.
+    pub offset: u64,
+    /// Total size of the log file in bytes.
+    pub total_size: u64,
+}
+
+/// Reads a slice of the current application log file
+///
+/// Reads at most `max_bytes` (default 256 KB) starting at `offset`. Without an offset
+/// the end of the file is returned, so huge TRACE logs never have to be loaded whole.
 #[tauri::command]
-pub fn read_app_log_file() -> Result<String, String> {
-    // Determine log file path - use APPDATA\com.rscoop.app\logs\rscoop.log on Windows
-    let log_file = if let Some(data_dir) = dirs::data_dir() {
-        data_dir.join("com.rscoop.app").join("logs").join("rscoop.log")
-    } else {
-        PathBuf::from("./logs/rscoop.log")
-    };
+pub fn read_app_log_file(
+    offset: Option<u64>,
+    max_bytes: Option<u64>,
+) -> Result<LogChunk, AppError> {
+    let log_file = current_log_file_path().map_err(AppError::Io)?;
 
-    // Read the log file
-    match fs::read_to_string(&log_file) {
-        Ok(content) => Ok(content),
-        Err(e) => {
-            if !log_file.exists() {
-                Ok(format!(
+    let mut file = match fs::File::open(&log_file) {
+        Ok(file) => file,
+        Err(_) if !log_file.exists() => {
+            return Ok(LogChunk {
+                content: format!(
                     "Log file not found at: {}\n\nLogs will be created after the first run.",
                     log_file.display()
-                ))
-            } else {
-                Err(format!("Failed to read log file: {}", e))
+                ),
+                offset: 0,
+                total_size: 0,
+            })
+        }
+        Err(e) => return Err(AppError::Io(format!("Failed to read log file: {}", e))),
+    };
+
+    let total_size = file
+        .metadata()
+        .map_err(|e| AppError::Io(format!("Failed to read log file: {}", e)))?
+        .len();
+    let max_bytes = max_bytes.unwrap_or(DEFAULT_LOG_TAIL_BYTES);
+    let offset = offset
+        .unwrap_or_else(|| total_size.saturating_sub(max_bytes))
+        .min(total_size);
+
+    let mut buffer = Vec::new();
+    file.seek(SeekFrom::Start(offset))
+        .and_then(|_| file.take(max_bytes).read_to_end(&mut buffer))
+        .map_err(|e| AppError::Io(format!("Failed to read log file: {}", e)))?;
+
+    Ok(LogChunk {
+        content: String::from_utf8_lossy(&buffer).to_string(),
+        offset,
+        total_size,
+    })
+}
+
+/// Metadata about a single log file in the log directory.
+#[derive(Serialize, Debug, Clone)]
+pub struct LogFileInfo {
+    pub path: String,
+    pub size_bytes: u64,
+    /// Last modification time in RFC 3339 format.
+    pub modified: String,
+}
+
+/// Lists all `*.log` files in the log directory, newest first.
+#[tauri::command]
+pub fn list_log_files() -> Result<Vec<LogFileInfo>, AppError> {
+    let log_dir = get_log_dir().map_err(AppError::Io)?;
+    if !log_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    Ok(log_files_newest_first(&log_dir)
+        .into_iter()
+        .filter_map(|(modified, path)| {
+            let metadata = fs::metadata(&path).ok()?;
+            let datetime: chrono::DateTime<Local> = modified.into();
+            Some(LogFileInfo {
+                path: path.to_string_lossy().to_string(),
+                size_bytes: metadata.len(),
+                modified: datetime.to_rfc3339(),
+            })
+        })
+        .collect())
+}
+
+/// Reads a log file, ensuring it resides inside the log directory
+#[tauri::command]
+pub fn read_log_file(path: String) -> Result<String, AppError> {
+    let log_dir = get_log_dir().map_err(AppError::Io)?;
+    let log_dir = log_dir
+        .canonicalize()
+        .map_err(|e| AppError::Io(format!("Failed to resolve log directory: {}", e)))?;
+    let requested = PathBuf::from(&path)
+        .canonicalize()
+        .map_err(|e| AppError::Io(format!("Failed to resolve log file {}: {}", path, e)))?;
+
+    if !requested.starts_with(&log_dir) || !requested.is_file() {
+        log::warn!("Rejected attempt to read file outside log directory: {}", path);
+        return Err(AppError::InvalidInput(format!("Not a log file: {}", path)));
+    }
+
+    fs::read_to_string(&requested)
+        .map_err(|e| AppError::Io(format!("Failed to read log file: {}", e)))
+}
+
+/// Starts watching the current log file and emits a `log-line` event for each appended line.
+///
+/// Any previously started tail is stopped. Truncation or replacement of the file
+/// (log rotation) is detected and the file is re-read from the beginning.
+#[tauri::command]
+pub fn start_log_tail(window: Window) -> Result<(), AppError> {
+    let generation = LOG_TAIL_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    let log_file = current_log_file_path().map_err(AppError::Io)?;
+    log::info!("Starting log tail for {}", log_file.display());
+
+    tauri::async_runtime::spawn(async move {
+        let initial = fs::metadata(&log_file).ok();
+        let mut position = initial.as_ref().map(|m| m.len()).unwrap_or(0);
+        let mut created = initial.and_then(|m| m.created().ok());
+        let mut pending = String::new();
+
+        while LOG_TAIL_GENERATION.load(Ordering::SeqCst) == generation {
+            tokio::time::sleep(std::time::Duration::from_millis(LOG_TAIL_POLL_MS)).await;
+
+            let metadata = match fs::metadata(&log_file) {
+                Ok(m) => m,
+                Err(_) => {
+                    // File is missing, probably mid-rotation; start over once it reappears
+                    position = 0;
+                    created = None;
+                    pending.clear();
+                    continue;
+                }
+            };
+
+            let current_created = metadata.created().ok();
+            if metadata.len() < position || (created.is_some() && current_created != created) {
+                position = 0;
+                pending.clear();
+            }
+            created = current_created;
+
+            if metadata.len() == position {
+                continue;
+            }
+
+            let mut buffer = Vec::new();
+            let read_result = fs::File::open(&log_file).and_then(|mut file| {
+                file.seek(SeekFrom::Start(position))?;
+                file.read_to_end(&mut buffer)
+            });
+            if read_result.is_err() {
+                continue;
+            }
+            position += buffer.len() as u64;
+            pending.push_str(&String::from_utf8_lossy(&buffer));
+
+            while let Some(idx) = pending.find('\n') {
+                let line: String = pending.drain(..=idx).collect();
+                let _ = window.emit(EVENT_LOG_LINE, line.trim_end());
             }
         }
+    });
+
+    Ok(())
+}
+
+/// Stops the log tail started by `start_log_tail`
+#[tauri::command]
+pub fn stop_log_tail() -> Result<(), AppError> {
+    LOG_TAIL_GENERATION.fetch_add(1, Ordering::SeqCst);
+    log::info!("Stopped log tail");
+    Ok(())
+}
+
+/// Number of most recent log files included in a support bundle
+const SUPPORT_BUNDLE_LOG_COUNT: usize = 5;
+
+// Settings keys whose values are replaced before being written to a support bundle
+const REDACTED_KEY_PATTERNS: &[&str] = &["key", "token", "password", "secret", "proxy"];
+
+/// Recursively replaces values of sensitive-looking keys with a placeholder
+fn redact_settings(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, val) in map.iter_mut() {
+                let lower = key.to_lowercase();
+                if REDACTED_KEY_PATTERNS.iter().any(|p| lower.contains(p)) {
+                    *val = serde_json::json!("<redacted>");
+                } else {
+                    redact_settings(val);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact_settings),
+        _ => {}
     }
 }
 
-/// Checks if factory reset marker exists
+/// Creates a zip archive at `dest` with recent logs, debug info, scoop installation
+/// status and a redacted copy of the settings, for attaching to bug reports.
 #[tauri::command]
-pub fn check_factory_reset_marker() -> Result<bool, String> {
-    if let Some(app_data_dir) = dirs::data_dir() {
-        let marker_file = app_data_dir.join(TAURI_APP_ID).join(FACTORY_RESET_MARKER);
-        if marker_file.exists() {
-            // Remove the marker after checking
-            let _ = fs::remove_file(&marker_file);
-            return Ok(true);
+pub async fn create_support_bundle(
+    state: State<'_, AppState>,
+    dest: String,
+) -> Result<String, AppError> {
+    log::info!("Creating support bundle at {}", dest);
+
+    let staging = tempfile::tempdir()
+        .map_err(|e| AppError::Io(format!("Failed to create staging directory: {}", e)))?;
+    let staging_path = staging.path().to_path_buf();
+
+    // Recent log files only; the log directory never holds WebView databases, but
+    // restrict to *.log files so nothing else can slip in.
+    let logs_dir = staging_path.join("logs");
+    fs::create_dir_all(&logs_dir)?;
+    for info in list_log_files()?.into_iter().take(SUPPORT_BUNDLE_LOG_COUNT) {
+        let source = PathBuf::from(&info.path);
+        if let Some(name) = source.file_name() {
+            if let Err(e) = fs::copy(&source, logs_dir.join(name)) {
+                log::warn!("Skipping log file {} in support bundle: {}", info.path, e);
+            }
         }
     }
+
+    let debug_info = get_debug_info(state.clone()).await?;
+    fs::write(
+        staging_path.join("debug_info.json"),
+        serde_json::to_string_pretty(&debug_info)
+            .map_err(|e| AppError::OperationFailed(e.to_string()))?,
+    )
+    .map_err(|e| AppError::Io(format!("Failed to write debug info: {}", e)))?;
+
+    let installation = crate::commands::status::check_scoop_installation(state)
+        .await
+        .map_err(AppError::OperationFailed)?;
+    fs::write(
+        staging_path.join("scoop_installation.json"),
+        serde_json::to_string_pretty(&installation)
+            .map_err(|e| AppError::OperationFailed(e.to_string()))?,
+    )
+    .map_err(|e| AppError::Io(format!("Failed to write scoop installation status: {}", e)))?;
+
+    let settings_path = resolve_app_data_dir()
+        .map_err(AppError::Io)?
+        .join(FRONTEND_STORE_FILE);
+    if let Ok(content) = fs::read_to_string(&settings_path) {
+        match serde_json::from_str::<serde_json::Value>(&content) {
+            Ok(mut settings) => {
+                redact_settings(&mut settings);
+                fs::write(
+                    staging_path.join("settings.redacted.json"),
+                    serde_json::to_string_pretty(&settings)
+                        .map_err(|e| AppError::OperationFailed(e.to_string()))?,
+                )
+                .map_err(|e| AppError::Io(format!("Failed to write settings: {}", e)))?;
+            }
+            Err(e) => log::warn!("Skipping unparsable settings in support bundle: {}", e),
+        }
+    }
+
+    let dest_path = PathBuf::from(&dest);
+    if let Some(parent) = dest_path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| AppError::Io(format!("Failed to create destination directory: {}", e)))?;
+    }
+
+    let command = format!(
+        "Compress-Archive -Path '{}' -DestinationPath '{}' -Force",
+        staging_path.join("*").display().to_string().replace('\'', "''"),
+        dest_path.display().to_string().replace('\'', "''")
+    );
+    let output = crate::commands::powershell::create_powershell_command(&command)
+        .output()
+        .await
+        .map_err(|e| AppError::OperationFailed(format!("Failed to run Compress-Archive: {}", e)))?;
+
+    if !output.status.success() {
+        return Err(AppError::OperationFailed(format!(
+            "Failed to create support bundle: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    log::info!("Support bundle written to {}", dest_path.display());
+    Ok(dest_path.to_string_lossy().to_string())
+}
+
+/// Checks if factory reset marker exists
+#[tauri::command]
+pub fn check_factory_reset_marker() -> Result<bool, AppError> {
+    let marker_file = resolve_app_data_dir()
+        .map_err(AppError::Io)?
+        .join(FACTORY_RESET_MARKER);
+    if marker_file.exists() {
+        // Remove the marker after checking
+        let _ = fs::remove_file(&marker_file);
+        return Ok(true);
+    }
     Ok(false)
 }
 
-/// Clears Tauri store configuration data
+/// Reports which restart markers currently exist, without removing them.
+///
+/// Returns `"factory-reset"` and/or `"webview-cleanup"`, so the UI can keep showing a
+/// "restart to finish cleanup" banner until the app is actually restarted.
 #[tauri::command]
-pub fn clear_store_data() -> Result<(), String> {
+pub fn pending_restart_reasons() -> Result<Vec<String>, AppError> {
+    let app_data_dir = resolve_app_data_dir().map_err(AppError::Io)?;
+
+    Ok([
+        (FACTORY_RESET_MARKER, "factory-reset"),
+        (WEBVIEW_CLEANUP_MARKER, "webview-cleanup"),
+    ]
+    .into_iter()
+    .filter(|(marker, _)| app_data_dir.join(marker).exists())
+    .map(|(_, reason)| reason.to_string())
+    .collect())
+}
+
+/// Clears Tauri store configuration data, both on disk and in the loaded store
+#[tauri::command]
+pub fn clear_store_data(app: AppHandle) -> Result<(), AppError> {
     log::info!("Starting store data cleanup");
-    
+
+    // Clear the in-memory store first so it cannot write the old values back
+    if let Err(e) = crate::commands::settings::clear_loaded_store(&app) {
+        log::warn!("Failed to clear in-memory store: {}", e);
+    }
+
+    let store_cleared = remove_store_files();
+    let marker_created = create_factory_reset_marker();
+
+    log::info!(
+        "Store cleanup completed. All files removed: {}, created marker: {}",
+        store_cleared,
+        marker_created
+    );
+
+    Ok(())
+}
+
+/// Removes only the legacy `settings.dat.bak` and `signals.dat.bak` backups, leaving
+/// the live configuration untouched.
+///
+/// Returns the number of removed files.
+#[tauri::command]
+pub fn prune_settings_backups() -> Result<usize, AppError> {
+    let backup_files = [
+        Some(resolve_app_data_dir().map_err(AppError::Io)?),
+        dirs::data_local_dir().map(|d| d.join(OLD_APP_DIR)),
+    ]
+    .into_iter()
+    .flatten()
+    .flat_map(|dir| {
+        [LEGACY_SETTINGS_FILE, LEGACY_SIGNALS_FILE]
+            .map(|file| dir.join(format!("{}{}", file, BACKUP_EXT)))
+    });
+
+    let mut removed = 0;
+    for backup_file in backup_files {
+        if backup_file.is_file() {
+            if safe_remove_file(&backup_file) {
+                removed += 1;
+            } else {
+                log::warn!("Failed to remove settings backup: {}", backup_file.display());
+            }
+        }
+    }
+
+    log::info!("Pruned {} settings backup files", removed);
+    Ok(removed)
+}
+
+/// Deletes `*.log` files from the log directory and its per-operation logs, optionally
+/// keeping the current `rscoop.log` that the logger writes to.
+///
+/// Returns the number of bytes freed.
+#[tauri::command]
+pub fn clear_logs(keep_current: bool) -> Result<u64, AppError> {
+    let log_dir = get_log_dir().map_err(AppError::Io)?;
+    let current_log = current_log_file_path().map_err(AppError::Io)?;
+    Ok(clear_log_files(
+        &log_dir,
+        keep_current.then_some(current_log.as_path()),
+    ))
+}
+
+/// Deletes the `*.log` files in `log_dir` and its operation logs, except the one named
+/// like `keep`. Returns the number of bytes freed.
+fn clear_log_files(log_dir: &std::path::Path, keep: Option<&std::path::Path>) -> u64 {
+    let mut freed = 0;
+    let mut removed = 0;
+    let app_logs = log_files_newest_first(log_dir)
+        .into_iter()
+        .filter(|(_, path)| keep.is_none_or(|keep| path.file_name() != keep.file_name()));
+    let operation_logs = log_files_newest_first(&log_dir.join(OPERATION_LOGS_DIR));
+    for (_, path) in app_logs.chain(operation_logs) {
+        let size = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        // Not `safe_remove_file`: its WebView2 lock patterns match every `.log` file
+        if remove_file_with_retries(&path, true) {
+            freed += size;
+            removed += 1;
+        } else {
+            log::warn!("Failed to remove log file: {}", path.display());
+        }
+    }
+
+    log::info!("Cleared {} log files, freed {} bytes", removed, freed);
+    freed
+}
+
+/// Removes the store files from the current and legacy data directories.
+///
+/// Returns `false` if any file could not be removed (usually because it is in use).
+fn remove_store_files() -> bool {
+    let app_data_dir = match resolve_app_data_dir() {
+        Ok(dir) => dir,
+        Err(e) => {
+            log::warn!("Failed to clear store files: {}", e);
+            return false;
+        }
+    };
+
     // Create list of files to clear using defined constants
     let store_files = vec![
         // New unified store files
-        dirs::data_dir().map(|d| d.join(TAURI_APP_ID).join(FRONTEND_STORE_FILE)),
-        dirs::data_dir().map(|d| d.join(TAURI_APP_ID).join(BACKEND_STORE_FILE)),
-        dirs::data_dir().map(|d| d.join(TAURI_APP_ID).join(VERSION_FILE)),
+        Some(app_data_dir.join(FRONTEND_STORE_FILE)),
+        Some(app_data_dir.join(BACKEND_STORE_FILE)),
+        Some(app_data_dir.join(VERSION_FILE)),
         // Backup files in new directory
-        dirs::data_dir().map(|d| d.join(TAURI_APP_ID).join(format!("{}{}", FRONTEND_STORE_FILE, BACKUP_EXT))),
-        dirs::data_dir().map(|d| d.join(TAURI_APP_ID).join(format!("{}{}", BACKEND_STORE_FILE, BACKUP_EXT))),
+        Some(app_data_dir.join(format!("{}{}", FRONTEND_STORE_FILE, BACKUP_EXT))),
+        Some(app_data_dir.join(format!("{}{}", BACKEND_STORE_FILE, BACKUP_EXT))),
         // Legacy files for migration cleanup
-        dirs::data_dir().map(|d| d.join(TAURI_APP_ID).join(LEGACY_SETTINGS_FILE)),
-        dirs::data_dir().map(|d| d.join(TAURI_APP_ID).join(LEGACY_SIGNALS_FILE)),
-        dirs::data_dir().map(|d| d.join(TAURI_APP_ID).join(LEGACY_STORE_FILE)),
+        Some(app_data_dir.join(LEGACY_SETTINGS_FILE)),
+        Some(app_data_dir.join(LEGACY_SIGNALS_FILE)),
+        Some(app_data_dir.join(LEGACY_STORE_FILE)),
         // Old rscoop directory - main files
         dirs::data_local_dir().map(|d| d.join(OLD_APP_DIR).join(LEGACY_SETTINGS_FILE)),
         dirs::data_local_dir().map(|d| d.join(OLD_APP_DIR).join(LEGACY_SIGNALS_FILE)),
@@ -459,43 +1603,48 @@ pub fn clear_store_data() -> Result<(), String> {
         }
     }
     
-    // Create a marker file to indicate factory reset
-    let mut marker_created = false;
-    if let Some(app_data_dir) = dirs::data_dir() {
-        let marker_file = app_data_dir.join(TAURI_APP_ID).join(FACTORY_RESET_MARKER);
-        if let Some(parent) = marker_file.parent() {
-            match fs::create_dir_all(parent) {
-                Ok(_) => {
-                    match fs::write(&marker_file, "Factory reset requested") {
-                        Ok(_) => {
-                            marker_created = true;
-                            log::info!("Created factory reset marker: {}", marker_file.display());
-                        }
-                        Err(e) => log::warn!("Failed to create factory reset marker: {}", e),
-                    }
-                }
-                Err(e) => log::warn!("Failed to create directory for marker: {}", e),
-            }
-        }
-    }
-    
-    log::info!("Store cleanup completed. Removed {} files, created marker: {}", cleared_count, marker_created);
-    
+    log::info!("Removed {} store files", cleared_count);
+
     if !failed_files.is_empty() {
         log::warn!("Failed to clear {} store files (likely in use):", failed_files.len());
         for path in &failed_files {
             log::warn!("  - {}", path.display());
         }
-        // Don't return error for locked files, they will be cleaned up on restart
+        // Locked files will be cleaned up on restart
+        return false;
+    }
+
+    true
+}
+
+/// Creates the marker file that signals a pending factory reset on next startup.
+fn create_factory_reset_marker() -> bool {
+    let app_data_dir = match resolve_app_data_dir() {
+        Ok(dir) => dir,
+        Err(e) => {
+            log::warn!("Failed to create factory reset marker: {}", e);
+            return false;
+        }
+    };
+    let marker_file = app_data_dir.join(FACTORY_RESET_MARKER);
+    match fs::create_dir_all(&app_data_dir)
+        .and_then(|_| fs::write(&marker_file, "Factory reset requested"))
+    {
+        Ok(_) => {
+            log::info!("Created factory reset marker: {}", marker_file.display());
+            true
+        }
+        Err(e) => {
+            log::warn!("Failed to create factory reset marker: {}", e);
+            false
+        }
     }
-    
-    Ok(())
 }
 
 /// Clears registry data on Windows
 #[tauri::command]
 #[cfg(windows)]
-pub fn clear_registry_data() -> Result<(), String> {
+pub fn clear_registry_data() -> Result<(), AppError> {
     log::info!("Attempting to clear Windows registry entries");
     
     use std::process::Command;
@@ -507,7 +1656,19 @@ pub fn clear_registry_data() -> Result<(), String> {
         Err(e) => log::warn!("Failed to cleanup startup registry entries: {}", e),
     }
     
-    // Clear registry entries using reg command
+    // Installer-generated uninstall entries may have GUID-suffixed names, so find them by content
+    for key in find_rscoop_uninstall_keys() {
+        let output = Command::new("reg").args(&["delete", &key, "/f"]).output();
+        match output {
+            Ok(result) if result.status.success() => {
+                log::info!("Successfully deleted registry key: {}", key);
+            }
+            Ok(_) => log::warn!("Could not delete registry key: {}", key),
+            Err(e) => log::warn!("Failed to execute registry command for {}: {}", key, e),
+        }
+    }
+
+    // Clear the known fixed registry entries using reg command
     let registry_keys = vec![
         r"HKEY_CURRENT_USER\Software\com.rscoop.app",
         r"HKEY_CURRENT_USER\Software\Rscoop",
@@ -537,21 +1698,67 @@ pub fn clear_registry_data() -> Result<(), String> {
     Ok(())
 }
 
+/// Enumerates the Uninstall hives and returns the full paths of subkeys whose
+/// `DisplayName` or `Publisher` mentions Rscoop.
+#[cfg(windows)]
+fn find_rscoop_uninstall_keys() -> Vec<String> {
+    use winreg::{enums::*, RegKey};
+
+    const UNINSTALL_PATH: &str = r"Software\Microsoft\Windows\CurrentVersion\Uninstall";
+    const UNINSTALL_PATH_WOW64: &str = r"Software\Wow6432Node\Microsoft\Windows\CurrentVersion\Uninstall";
+
+    let uninstall_hives = [
+        (RegKey::predef(HKEY_CURRENT_USER), "HKEY_CURRENT_USER", UNINSTALL_PATH),
+        (RegKey::predef(HKEY_LOCAL_MACHINE), "HKEY_LOCAL_MACHINE", UNINSTALL_PATH),
+        (RegKey::predef(HKEY_LOCAL_MACHINE), "HKEY_LOCAL_MACHINE", UNINSTALL_PATH_WOW64),
+    ];
+
+    let mut matches = Vec::new();
+    for (hive, hive_name, path) in &uninstall_hives {
+        let uninstall = match hive.open_subkey_with_flags(path, KEY_READ) {
+            Ok(key) => key,
+            Err(_) => continue,
+        };
+
+        for subkey_name in uninstall.enum_keys().filter_map(|k| k.ok()) {
+            let subkey = match uninstall.open_subkey_with_flags(&subkey_name, KEY_READ) {
+                Ok(key) => key,
+                Err(_) => continue,
+            };
+
+            let is_rscoop = ["DisplayName", "Publisher"].iter().any(|value_name| {
+                subkey
+                    .get_value::<String, _>(value_name)
+                    .map(|v| v.to_lowercase().contains("rscoop"))
+                    .unwrap_or(false)
+            });
+
+            if is_rscoop {
+                let full_path = format!("{}\\{}\\{}", hive_name, path, subkey_name);
+                log::info!("Found Rscoop uninstall registry key: {}", full_path);
+                matches.push(full_path);
+            }
+        }
+    }
+
+    matches
+}
+
 #[cfg(not(windows))]
 #[tauri::command]
-pub fn clear_registry_data() -> Result<(), String> {
+pub fn clear_registry_data() -> Result<(), AppError> {
     // Not applicable on non-Windows platforms
     Ok(())
 }
 
 /// Clears WebView cache data
 #[tauri::command]
-pub fn clear_webview_cache() -> Result<(), String> {
+pub fn clear_webview_cache() -> Result<(), AppError> {
     log::info!("Attempting to clear WebView cache");
     
     // Try to clear cache from both new and old locations
     let cache_dirs = vec![
-        dirs::data_dir().map(|d| d.join(TAURI_APP_ID)),
+        Some(resolve_app_data_dir().map_err(AppError::Io)?),
         dirs::data_local_dir().map(|d| d.join(OLD_APP_DIR)),
     ];
     
@@ -576,42 +1783,110 @@ pub fn clear_webview_cache() -> Result<(), String> {
     Ok(())
 }
 
-/// Schedules WebView cache cleanup for next startup
+/// Report of a soft WebView cache cleanup
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct WebViewCacheReport {
+    pub cleared: Vec<String>,
+    pub skipped: Vec<String>,
+}
+
+/// Clears only the WebView caches that hold no user state (GPUCache, Code Cache).
+///
+/// Unlike a factory reset this never touches IndexedDB or Local/Session Storage,
+/// so nothing is logged out. No WebView processes are terminated; directories that
+/// are still in use are reported as skipped.
 #[tauri::command]
-pub fn schedule_webview_cleanup() -> Result<(), String> {
-    if let Some(app_data_dir) = dirs::data_dir() {
-        let marker_file = app_data_dir.join(TAURI_APP_ID).join(WEBVIEW_CLEANUP_MARKER);
-        if let Some(parent) = marker_file.parent() {
-            match fs::create_dir_all(parent) {
-                Ok(_) => {
-                    match fs::write(&marker_file, "Cleanup WebView cache on next startup") {
-                        Ok(_) => {
-                            log::info!("Scheduled WebView cache cleanup for next startup");
-                        }
-                        Err(e) => log::warn!("Failed to schedule WebView cache cleanup: {}", e),
-                    }
-                }
-                Err(e) => log::warn!("Failed to create directory for WebView cleanup marker: {}", e),
+pub fn clear_webview_cache_soft() -> Result<WebViewCacheReport, AppError> {
+    log::info!("Attempting soft WebView cache cleanup");
+
+    let mut roots: Vec<PathBuf> = Vec::new();
+    for base in [dirs::data_dir(), dirs::data_local_dir()].into_iter().flatten() {
+        let app_dir = base.join(TAURI_APP_ID);
+        roots.push(app_dir.join("EBWebView").join("Default"));
+        roots.push(app_dir);
+    }
+
+    let mut report = WebViewCacheReport::default();
+    for root in roots.iter().filter(|r| r.is_dir()) {
+        let entries = match fs::read_dir(root) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if !path.is_dir() || !is_webview_locked_dir(&path) {
+                continue;
+            }
+
+            let name = entry.file_name().to_string_lossy().to_string();
+            let display = path.display().to_string();
+            if !WEBVIEW_SAFE_CACHE_DIRS.contains(&name.as_str()) {
+                log::debug!("Keeping WebView state directory: {}", display);
+                report.skipped.push(display);
+            } else if remove_dir_with_retries(&path, true) {
+                log::info!("Removed WebView cache dir: {}", display);
+                report.cleared.push(display);
+            } else {
+                log::warn!("WebView cache dir is in use, skipped: {}", display);
+                report.skipped.push(display);
             }
         }
     }
+
+    log::info!(
+        "Soft WebView cache cleanup completed: {} cleared, {} skipped",
+        report.cleared.len(),
+        report.skipped.len()
+    );
+    Ok(report)
+}
+
+/// Schedules WebView cache cleanup for next startup
+#[tauri::command]
+pub fn schedule_webview_cleanup() -> Result<(), AppError> {
+    let app_data_dir = resolve_app_data_dir().map_err(AppError::Io)?;
+    fs::create_dir_all(&app_data_dir)
+        .and_then(|_| {
+            fs::write(
+                app_data_dir.join(WEBVIEW_CLEANUP_MARKER),
+                "Cleanup WebView cache on next startup",
+            )
+        })
+        .map_err(|e| AppError::Io(format!("Failed to schedule WebView cache cleanup: {}", e)))?;
+    log::info!("Scheduled WebView cache cleanup for next startup");
     Ok(())
 }
 
-/// Checks if WebView cleanup is scheduled
+/// Cancels a scheduled WebView cleanup by removing its marker.
+///
+/// Returns true if a scheduled cleanup was cancelled.
 #[tauri::command]
-pub fn is_webview_cleanup_scheduled() -> Result<bool, String> {
-    if let Some(app_data_dir) = dirs::data_dir() {
-        let marker_file = app_data_dir.join(TAURI_APP_ID).join(WEBVIEW_CLEANUP_MARKER);
-        Ok(marker_file.exists())
-    } else {
-        Ok(false)
+pub fn cancel_scheduled_webview_cleanup() -> Result<bool, AppError> {
+    let marker_file = resolve_app_data_dir()
+        .map_err(AppError::Io)?
+        .join(WEBVIEW_CLEANUP_MARKER);
+    if !marker_file.exists() {
+        return Ok(false);
     }
+
+    fs::remove_file(&marker_file)?;
+    log::info!("Cancelled scheduled WebView cache cleanup");
+    Ok(true)
+}
+
+/// Checks if WebView cleanup is scheduled
+#[tauri::command]
+pub fn is_webview_cleanup_scheduled() -> Result<bool, AppError> {
+    Ok(resolve_app_data_dir()
+        .map_err(AppError::Io)?
+        .join(WEBVIEW_CLEANUP_MARKER)
+        .exists())
 }
 
 /// Performs WebView cleanup if scheduled
 #[tauri::command]
-pub fn perform_scheduled_webview_cleanup() -> Result<(), String> {
+pub fn perform_scheduled_webview_cleanup() -> Result<(), AppError> {
     // Check if cleanup is scheduled
     if !is_webview_cleanup_scheduled()? {
         return Ok(());
@@ -623,11 +1898,11 @@ pub fn perform_scheduled_webview_cleanup() -> Result<(), String> {
     clear_webview_cache()?;
     
     // Remove the marker
-    if let Some(app_data_dir) = dirs::data_dir() {
-        let marker_file = app_data_dir.join(TAURI_APP_ID).join(WEBVIEW_CLEANUP_MARKER);
-        if marker_file.exists() {
-            let _ = fs::remove_file(&marker_file);
-        }
+    let marker_file = resolve_app_data_dir()
+        .map_err(AppError::Io)?
+        .join(WEBVIEW_CLEANUP_MARKER);
+    if marker_file.exists() {
+        let _ = fs::remove_file(&marker_file);
     }
     
     log::info!("Completed scheduled WebView cache cleanup");
@@ -636,16 +1911,30 @@ pub fn perform_scheduled_webview_cleanup() -> Result<(), String> {
 
 /// Final cleanup to be called during application shutdown
 #[tauri::command]
-pub fn final_cleanup_on_exit() -> Result<(), String> {
+pub fn final_cleanup_on_exit(state: State<'_, AppState>) -> Result<(), AppError> {
     log::info!("Performing final cleanup before exit");
-    
+
+    // Stop the scheduler from writing settings and wait for any in-flight run to finish,
+    // otherwise the store file could be recreated right after we delete it.
+    const SCHEDULER_WAIT_MS: u64 = 10_000;
+    state.request_shutdown();
+    let wait_start = std::time::Instant::now();
+    while state.is_scheduler_busy() {
+        if wait_start.elapsed().as_millis() as u64 >= SCHEDULER_WAIT_MS {
+            log::warn!("Scheduler still busy after {}ms, continuing cleanup", SCHEDULER_WAIT_MS);
+            break;
+        }
+        std::thread::sleep(std::time::Duration::from_millis(50));
+    }
+
     // Give WebView processes a moment to release files
     std::thread::sleep(std::time::Duration::from_millis(1000));
     
     // Try to remove any remaining configuration files
+    let app_data_dir = resolve_app_data_dir().map_err(AppError::Io)?;
     let final_cleanup_files = vec![
-        dirs::data_dir().map(|d| d.join(TAURI_APP_ID).join(FRONTEND_STORE_FILE)),
-        dirs::data_dir().map(|d| d.join(TAURI_APP_ID).join(BACKEND_STORE_FILE)),
+        Some(app_data_dir.join(FRONTEND_STORE_FILE)),
+        Some(app_data_dir.join(BACKEND_STORE_FILE)),
         dirs::data_local_dir().map(|d| d.join(OLD_APP_DIR).join(LEGACY_SETTINGS_FILE)),
         dirs::data_local_dir().map(|d| d.join(OLD_APP_DIR).join(LEGACY_SIGNALS_FILE)),
     ];
@@ -666,15 +1955,59 @@ pub fn final_cleanup_on_exit() -> Result<(), String> {
     Ok(())
 }
 
-fn get_log_dir() -> Option<PathBuf> {
-    // First try to get the Tauri app data directory
-    if let Some(app_data_dir) = dirs::data_dir() {
-        let app_data_dir = app_data_dir.join(TAURI_APP_ID);
-        if app_data_dir.exists() {
-            return Some(app_data_dir.join("logs"));
-        }
+/// Resolves the path of the current log file (APPDATA\com.rscoop.app\logs\rscoop.log on Windows)
+fn current_log_file_path() -> Result<PathBuf, String> {
+    resolve_app_data_dir().map(|dir| dir.join("logs").join("rscoop.log"))
+}
+
+/// Resolves the log directory inside the directory returned by `resolve_existing_data_dir`.
+fn get_log_dir() -> Result<PathBuf, String> {
+    resolve_existing_data_dir().map(|dir| dir.join("logs"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clear_regular_directory_keeps_logs_but_removes_user_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let notes = dir.path().join("myrscoopnotes.txt");
+        let logs_dir = dir.path().join("logs");
+        let log_file = logs_dir.join("rscoop.log");
+        fs::write(&notes, "notes").unwrap();
+        fs::create_dir_all(&logs_dir).unwrap();
+        fs::write(&log_file, "log").unwrap();
+
+        assert!(!is_file_locked_by_current_process(&notes));
+        assert!(is_file_locked_by_current_process(&log_file));
+
+        let summary = clear_regular_directory(dir.path(), false).unwrap();
+        assert!(summary.failed.is_empty());
+        assert_eq!(summary.removed, 1);
+        assert_eq!(summary.locked, 1);
+        assert!(!notes.exists());
+        assert!(log_file.exists());
+    }
+
+    #[test]
+    fn test_clear_log_files_removes_app_and_operation_logs() {
+        let dir = tempfile::tempdir().unwrap();
+        let operations_dir = dir.path().join(OPERATION_LOGS_DIR);
+        fs::create_dir_all(&operations_dir).unwrap();
+        let current = dir.path().join("rscoop.log");
+        let old = dir.path().join("rscoop_2024-01-01.log");
+        let operation = operations_dir.join("install-foo-1-0.log");
+        fs::write(&current, "current").unwrap();
+        fs::write(&old, "0123456789").unwrap();
+        fs::write(&operation, "12345").unwrap();
+
+        assert_eq!(clear_log_files(dir.path(), Some(&current)), 15);
+        assert!(current.exists());
+        assert!(!old.exists());
+        assert!(!operation.exists());
+
+        assert_eq!(clear_log_files(dir.path(), None), 7);
+        assert!(!current.exists());
     }
-    
-    // Fallback to the old rscoop directory
-    dirs::data_local_dir().map(|d| d.join(OLD_APP_DIR).join("logs"))
 }
@@ -0,0 +1,95 @@
+//! Commands for inspecting dependencies between installed Scoop packages.
+use crate::commands::installed::get_installed_packages_full;
+use crate::commands::search::get_manifests;
+use crate::state::AppState;
+use rayon::prelude::*;
+use serde_json::Value;
+use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use tauri::{AppHandle, Runtime, State};
+
+/// Extracts the package names listed in a manifest's `depends` field.
+///
+/// `depends` may be a single string or an array, and entries may be bucket-qualified
+/// (`main/7zip`), in which case only the package name is returned.
+pub(crate) fn manifest_depends(manifest: &Value) -> Vec<String> {
+    let entries: Vec<&str> = match manifest.get("depends") {
+        Some(Value::String(s)) => vec![s.as_str()],
+        Some(Value::Array(arr)) => arr.iter().filter_map(|v| v.as_str()).collect(),
+        _ => Vec::new(),
+    };
+
+    entries
+        .into_iter()
+        .filter_map(|entry| entry.rsplit('/').next())
+        .map(|name| name.trim().to_lowercase())
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+/// Reads the `depends` field of a manifest file, returning an empty list on any error.
+fn read_manifest_depends(path: &Path) -> Vec<String> {
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str::<Value>(&content).ok())
+        .map(|json| manifest_depends(&json))
+        .unwrap_or_default()
+}
+
+/// Finds installed packages that look like leftover dependencies.
+///
+/// Scoop does not record why a package was installed, so a package is treated as a
+/// dependency when some manifest in the local buckets lists it in `depends`. It is
+/// reported as orphaned when no remaining installed app depends on it.
+#[tauri::command]
+pub async fn find_orphaned_packages<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+) -> Result<Vec<String>, String> {
+    log::info!("Searching for orphaned dependency packages");
+
+    let installed = get_installed_packages_full(app.clone(), state.clone()).await?;
+    let scoop_path = state.scoop_path();
+    let (manifest_paths, _) = get_manifests(app).await?;
+
+    let installed_names: Vec<String> = installed.iter().map(|p| p.name.clone()).collect();
+
+    let orphans = tokio::task::spawn_blocking(move || {
+        // Reverse-dependency set: everything a remaining installed app depends on.
+        let required: HashSet<String> = installed_names
+            .par_iter()
+            .flat_map_iter(|name| {
+                read_manifest_depends(
+                    &scoop_path
+                        .join("apps")
+                        .join(name)
+                        .join("current")
+                        .join("manifest.json"),
+                )
+            })
+            .collect();
+
+        // Every package some bucket manifest declares as a dependency.
+        let known_dependencies: HashSet<String> = manifest_paths
+            .par_iter()
+            .flat_map_iter(|path| read_manifest_depends(path))
+            .collect();
+
+        let mut orphans: Vec<String> = installed_names
+            .into_iter()
+            .filter(|name| name != "scoop")
+            .filter(|name| {
+                let key = name.to_lowercase();
+                known_dependencies.contains(&key) && !required.contains(&key)
+            })
+            .collect();
+        orphans.sort();
+        orphans
+    })
+    .await
+    .map_err(|e| format!("Failed to scan for orphaned packages: {}", e))?;
+
+    log::info!("Found {} orphaned packages", orphans.len());
+    Ok(orphans)
+}
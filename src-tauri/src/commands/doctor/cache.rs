@@ -1,11 +1,15 @@
 //! Commands for managing the Scoop cache.
+use crate::commands::doctor::disk_usage::invalidate_disk_usage;
 use crate::commands::installed::get_installed_packages_full;
+use crate::commands::powershell;
 use crate::state::AppState;
+use crate::utils::locate_package_manifest;
 use rayon::prelude::*;
 use serde::Serialize;
-use std::collections::HashSet;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use tauri::{AppHandle, Runtime, State};
 
 /// Represents a single entry in the Scoop cache.
@@ -18,6 +22,8 @@ pub struct CacheEntry {
     pub file_name: String,
     pub is_versioned_install: bool,
     pub is_safe_to_delete: bool,
+    /// Integrity problem found by `verify_cache`, if any.
+    pub issue: Option<String>,
 }
 
 /// Parses a `CacheEntry` from a given file path.
@@ -50,9 +56,49 @@ fn parse_cache_entry_from_path(
         file_name,
         is_versioned_install,
         is_safe_to_delete: !is_versioned_install,
+        issue: None,
     })
 }
 
+/// Number of files in the Scoop cache and their combined size.
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CacheSummary {
+    pub entry_count: usize,
+    pub total_bytes: u64,
+}
+
+/// Returns a quick summary of the cache directory by stat-ing its files.
+///
+/// Unlike `list_cache_contents` this does not parse entries or look up installed
+/// packages. A missing cache directory yields zeros.
+#[tauri::command]
+pub fn get_cache_summary(state: State<'_, AppState>) -> Result<CacheSummary, String> {
+    let cache_path = state.scoop_path().join("cache");
+    if !cache_path.is_dir() {
+        return Ok(CacheSummary::default());
+    }
+
+    let summary = fs::read_dir(&cache_path)
+        .map_err(|e| format!("Failed to read cache directory: {}", e))?
+        .filter_map(Result::ok)
+        .filter_map(|entry| entry.metadata().ok())
+        .filter(|metadata| metadata.is_file())
+        .fold(CacheSummary::default(), |mut summary, metadata| {
+            summary.entry_count += 1;
+            summary.total_bytes += metadata.len();
+            summary
+        });
+
+    log::debug!(
+        "Cache summary: {} entries, {} bytes",
+        summary.entry_count,
+        summary.total_bytes
+    );
+
+    Ok(summary)
+}
+
 /// Lists all entries in the Scoop cache directory with version-awareness.
 ///
 /// This function reads the cache directory, parses each file to extract cache information,
@@ -100,6 +146,210 @@ pub async fn list_cache_contents<R: Runtime>(
     Ok(entries)
 }
 
+/// Verifies the integrity of cached downloads.
+///
+/// Cache files follow Scoop's `name#version#url` naming convention. An entry is
+/// flagged when it is empty, or when its SHA256 hash does not match any hash
+/// listed in the package manifest for the same version. Only flagged entries are
+/// returned.
+///
+/// # Arguments
+/// * `name` - An optional package name to restrict the check to.
+#[tauri::command]
+pub async fn verify_cache<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+    name: Option<String>,
+) -> Result<Vec<CacheEntry>, String> {
+    log::info!("Verifying cache contents (package filter: {:?})", name);
+
+    let scoop_path = state.scoop_path();
+    let cache_path = scoop_path.join("cache");
+
+    if !cache_path.is_dir() {
+        log::warn!("Scoop cache directory not found at: {:?}", cache_path);
+        return Ok(vec![]);
+    }
+
+    let installed_packages = get_installed_packages_full(app, state).await?;
+    let versioned_packages: HashSet<String> = installed_packages
+        .iter()
+        .filter(|pkg| pkg.is_versioned_install)
+        .map(|pkg| pkg.name.clone())
+        .collect();
+
+    let read_dir =
+        fs::read_dir(&cache_path).map_err(|e| format!("Failed to read cache directory: {}", e))?;
+
+    let entries: Vec<CacheEntry> = read_dir
+        .filter_map(Result::ok)
+        .filter_map(|entry| parse_cache_entry_from_path(&entry.path(), &versioned_packages))
+        .filter(|entry| match &name {
+            Some(filter) => entry.name.eq_ignore_ascii_case(filter),
+            None => true,
+        })
+        .collect();
+
+    let mut flagged = Vec::new();
+    let mut to_hash: Vec<(CacheEntry, HashSet<String>)> = Vec::new();
+    let mut manifest_hashes: HashMap<(String, String), Option<HashSet<String>>> = HashMap::new();
+
+    for mut entry in entries {
+        if entry.length == 0 {
+            entry.issue = Some("Zero-byte file".to_string());
+            flagged.push(entry);
+            continue;
+        }
+
+        let expected = manifest_hashes
+            .entry((entry.name.clone(), entry.version.clone()))
+            .or_insert_with(|| expected_sha256_hashes(&scoop_path, &entry.name, &entry.version))
+            .clone();
+
+        if let Some(expected) = expected {
+            to_hash.push((entry, expected));
+        }
+    }
+
+    if !to_hash.is_empty() {
+        let paths: Vec<PathBuf> = to_hash
+            .iter()
+            .map(|(entry, _)| cache_path.join(&entry.file_name))
+            .collect();
+        let actual = compute_sha256_hashes(&paths).await?;
+
+        for ((mut entry, expected), path) in to_hash.into_iter().zip(paths) {
+            match actual.get(&path.to_string_lossy().to_lowercase()) {
+                Some(hash) if expected.contains(hash) => {}
+                Some(hash) => {
+                    entry.issue = Some(format!("Hash mismatch (got sha256:{})", hash));
+                    flagged.push(entry);
+                }
+                None => {
+                    log::warn!("Could not compute hash for cache file: {}", entry.file_name);
+                }
+            }
+        }
+    }
+
+    flagged.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
+
+    log::info!("Cache verification flagged {} entries", flagged.len());
+
+    Ok(flagged)
+}
+
+/// Collects the SHA256 hashes listed in a package manifest, including per-architecture ones.
+///
+/// Returns `None` when the manifest cannot be found, describes a different version than
+/// the cached file, or lists no SHA256 hashes.
+fn expected_sha256_hashes(scoop_path: &Path, name: &str, version: &str) -> Option<HashSet<String>> {
+    let (manifest_path, _) = locate_package_manifest(scoop_path, name, None).ok()?;
+    let content = fs::read_to_string(manifest_path).ok()?;
+    let manifest: Value = serde_json::from_str(&content).ok()?;
+
+    if manifest.get("version").and_then(Value::as_str) != Some(version) {
+        return None;
+    }
+
+    let mut hashes = HashSet::new();
+    collect_sha256(manifest.get("hash"), &mut hashes);
+    if let Some(architectures) = manifest.get("architecture").and_then(Value::as_object) {
+        for arch in architectures.values() {
+            collect_sha256(arch.get("hash"), &mut hashes);
+        }
+    }
+
+    if hashes.is_empty() {
+        None
+    } else {
+        Some(hashes)
+    }
+}
+
+/// Adds the SHA256 hashes from a manifest `hash` field (a string or an array) to `hashes`.
+///
+/// Scoop treats hashes without an algorithm prefix as SHA256.
+fn collect_sha256(value: Option<&Value>, hashes: &mut HashSet<String>) {
+    let values: Vec<&str> = match value {
+        Some(Value::String(hash)) => vec![hash.as_str()],
+        Some(Value::Array(items)) => items.iter().filter_map(Value::as_str).collect(),
+        _ => return,
+    };
+
+    for hash in values {
+        let hash = hash.trim().to_lowercase();
+        match hash.split_once(':') {
+            Some(("sha256", digest)) => {
+                hashes.insert(digest.to_string());
+            }
+            Some(_) => {}
+            None => {
+                hashes.insert(hash);
+            }
+        }
+    }
+}
+
+/// Upper bound on the length of the quoted path list passed to one `Get-FileHash` call,
+/// well under the ~32K character limit of a Windows command line.
+const HASH_BATCH_MAX_CHARS: usize = 16_000;
+
+/// Computes SHA256 hashes for the given files, with one `Get-FileHash` call per batch of
+/// paths that fits within `HASH_BATCH_MAX_CHARS`.
+///
+/// The returned map is keyed by the lowercased file path.
+async fn compute_sha256_hashes(paths: &[PathBuf]) -> Result<HashMap<String, String>, String> {
+    let quoted: Vec<String> = paths
+        .iter()
+        .map(|p| format!("'{}'", p.to_string_lossy().replace('\'', "''")))
+        .collect();
+
+    let mut hashes = HashMap::new();
+    let mut batch: Vec<&str> = Vec::new();
+    let mut batch_len = 0;
+    for path in &quoted {
+        if !batch.is_empty() && batch_len + path.len() + 1 > HASH_BATCH_MAX_CHARS {
+            hashes.extend(hash_batch(&batch.join(",")).await?);
+            batch.clear();
+            batch_len = 0;
+        }
+        batch_len += path.len() + 1;
+        batch.push(path);
+    }
+    if !batch.is_empty() {
+        hashes.extend(hash_batch(&batch.join(",")).await?);
+    }
+
+    Ok(hashes)
+}
+
+/// Runs `Get-FileHash` on a comma-separated list of quoted paths.
+async fn hash_batch(path_list: &str) -> Result<HashMap<String, String>, String> {
+    let command = format!(
+        "Get-FileHash -Algorithm SHA256 -LiteralPath {} | ForEach-Object {{ \"$($_.Hash)|$($_.Path)\" }}",
+        path_list
+    );
+
+    let output = powershell::create_powershell_command(&command)
+        .output()
+        .await
+        .map_err(|e| format!("Failed to compute cache file hashes: {}", e))?;
+
+    if !output.status.success() {
+        log::warn!(
+            "Get-FileHash reported errors: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| line.trim().split_once('|'))
+        .map(|(hash, path)| (path.to_lowercase(), hash.to_lowercase()))
+        .collect())
+}
+
 /// Clears specified files or the entire Scoop cache, with version-awareness.
 ///
 /// # Arguments
@@ -123,19 +373,21 @@ pub async fn clear_cache<R: Runtime>(
     }
 
     // Get versioned packages to avoid deleting their cache
-    let installed_packages = get_installed_packages_full(app, state).await?;
+    let installed_packages = get_installed_packages_full(app, state.clone()).await?;
     let versioned_packages: HashSet<String> = installed_packages
         .iter()
         .filter(|pkg| pkg.is_versioned_install)
         .map(|pkg| pkg.name.clone())
         .collect();
 
-    match files {
+    let result = match files {
         Some(files_to_delete) if !files_to_delete.is_empty() => {
             clear_specific_files_safe(&cache_path, &files_to_delete, &versioned_packages)
         }
         _ => clear_safe_cache(&cache_path, &versioned_packages),
-    }
+    };
+    invalidate_disk_usage(&state).await;
+    result
 }
 
 /// Removes a specific list of files from the cache directory, avoiding versioned installs.
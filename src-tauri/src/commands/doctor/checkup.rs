@@ -30,7 +30,7 @@ pub struct CheckupItem {
 }
 
 /// Checks if Git is installed and available in the PATH.
-async fn check_git_installed() -> CheckupItem {
+pub(crate) async fn check_git_installed() -> CheckupItem {
     let git_installed = create_powershell_command("git --version")
         .output()
         .await
@@ -1,8 +1,15 @@
 //! Commands for cleaning up Scoop apps and cache.
+use crate::commands::auto_cleanup::compare_versions;
+use crate::commands::doctor::disk_usage::invalidate_disk_usage;
 use crate::commands::installed::get_installed_packages_full;
+use crate::commands::operation_history;
 use crate::commands::powershell;
+use crate::error::AppError;
 use crate::state::AppState;
-use tauri::{AppHandle, Runtime, State, Window};
+use std::collections::HashSet;
+use std::path::Path;
+use std::time::Instant;
+use tauri::{AppHandle, Manager, Runtime, State, Window};
 
 /// Runs a specific Scoop cleanup command and streams its output.
 ///
@@ -16,9 +23,11 @@ async fn run_cleanup_command(
     command: &str,
     operation_name: &str,
     operation_id: &str,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     log::info!("Executing cleanup command: {}", command);
-    
+
+    let app = window.app_handle().clone();
+    let started = Instant::now();
     let result = powershell::run_and_stream_command(
         window,
         command.to_string(),
@@ -27,6 +36,7 @@ async fn run_cleanup_command(
         powershell::EVENT_FINISHED,
         powershell::EVENT_CANCEL,
         Some(operation_id.to_string()),
+        None,
     )
     .await;
     
@@ -34,19 +44,42 @@ async fn run_cleanup_command(
         Ok(_) => log::info!("Successfully completed cleanup command: {}", command),
         Err(e) => log::error!("Failed to execute cleanup command '{}': {}", command, e),
     }
-    
-    result
+    operation_history::record_operation(&app, "cleanup", operation_name, started, &result);
+    invalidate_disk_usage(&app.state::<AppState>()).await;
+
+    result.map_err(AppError::OperationFailed)
+}
+
+/// Settings key holding app names that must never be cleaned up.
+pub const CLEANUP_EXCLUDE_KEY: &str = "cleanup.excludeApps";
+
+/// Reads the list of apps protected from cleanup (lowercased).
+fn read_cleanup_excludes<R: Runtime>(app: &AppHandle<R>) -> HashSet<String> {
+    crate::commands::settings::get_config_value(app.clone(), CLEANUP_EXCLUDE_KEY.to_string())
+        .ok()
+        .flatten()
+        .and_then(|v| v.as_array().cloned())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str().map(|s| s.to_lowercase()))
+                .collect()
+        })
+        .unwrap_or_default()
 }
 
 /// Cleans up old versions of all installed apps, with an option to include/exclude versioned installs.
+///
+/// Apps listed in the `cleanup.excludeApps` setting are left untouched as well.
 #[tauri::command]
 pub async fn cleanup_all_apps<R: Runtime>(
     window: Window,
     app: AppHandle<R>,
     state: State<'_, AppState>,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     log::info!("Running cleanup of old app versions");
 
+    let excluded = read_cleanup_excludes(&app);
+
     // Get all installed packages to identify versioned installs
     let installed_packages_result = get_installed_packages_full(app, state.clone()).await;
     
@@ -57,7 +90,10 @@ pub async fn cleanup_all_apps<R: Runtime>(
         },
         Err(e) => {
             log::error!("Failed to retrieve installed packages: {}", e);
-            return Err(format!("Failed to retrieve installed packages: {}", e));
+            return Err(AppError::OperationFailed(format!(
+                "Failed to retrieve installed packages: {}",
+                e
+            )));
         }
     };
 
@@ -67,21 +103,38 @@ pub async fn cleanup_all_apps<R: Runtime>(
         .filter(|pkg| pkg.is_versioned_install)
         .count();
 
-    if versioned_count > 0 {
-        log::warn!(
-            "Found {} versioned installs. These will be EXCLUDED from cleanup to preserve specific versions.", 
-            versioned_count
+    let protected: Vec<String> = installed_packages
+        .iter()
+        .filter(|pkg| !pkg.is_versioned_install && excluded.contains(&pkg.name.to_lowercase()))
+        .map(|pkg| pkg.name.clone())
+        .collect();
+
+    if !protected.is_empty() {
+        log::info!(
+            "Protecting {} apps from cleanup (cleanup.excludeApps): {}",
+            protected.len(),
+            protected.join(", ")
         );
+    }
+
+    if versioned_count > 0 || !protected.is_empty() {
+        if versioned_count > 0 {
+            log::warn!(
+                "Found {} versioned installs. These will be EXCLUDED from cleanup to preserve specific versions.", 
+                versioned_count
+            );
+        }
 
-        // Get only regular packages (non-versioned installs)
+        // Get only regular packages (non-versioned, non-excluded installs)
         let regular_packages: Vec<String> = installed_packages
             .iter()
             .filter(|pkg| !pkg.is_versioned_install)
+            .filter(|pkg| !excluded.contains(&pkg.name.to_lowercase()))
             .map(|pkg| pkg.name.clone())
             .collect();
 
         if regular_packages.is_empty() {
-            log::info!("All packages are versioned installs - no cleanup needed");
+            log::info!("All packages are versioned installs or excluded - no cleanup needed");
             return Ok(());
         }
 
@@ -102,12 +155,58 @@ pub async fn cleanup_all_apps<R: Runtime>(
 
 /// Cleans up old versions of ALL apps, including versioned installs (DANGEROUS).
 /// This is equivalent to the original `scoop cleanup --all` command.
+///
+/// Apps in `cleanup.excludeApps` are still skipped unless `force_ignore_excludes` is set.
 #[tauri::command]
-pub async fn cleanup_all_apps_force(window: Window) -> Result<(), String> {
+pub async fn cleanup_all_apps_force<R: Runtime>(
+    window: Window,
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+    force_ignore_excludes: Option<bool>,
+) -> Result<(), AppError> {
     log::warn!("Running FORCE cleanup of ALL app versions (including versioned installs)");
+
+    let excluded = if force_ignore_excludes.unwrap_or(false) {
+        HashSet::new()
+    } else {
+        read_cleanup_excludes(&app)
+    };
+
+    if excluded.is_empty() {
+        return run_cleanup_command(
+            window,
+            "scoop cleanup --all",
+            "Force Cleanup All App Versions",
+            "cleanup-force",
+        )
+        .await;
+    }
+
+    let installed_packages = get_installed_packages_full(app, state)
+        .await
+        .map_err(AppError::OperationFailed)?;
+    let (protected, packages): (Vec<String>, Vec<String>) = installed_packages
+        .into_iter()
+        .map(|pkg| pkg.name)
+        .partition(|name| excluded.contains(&name.to_lowercase()));
+
+    if !protected.is_empty() {
+        log::info!(
+            "Protecting {} apps from force cleanup (cleanup.excludeApps): {}",
+            protected.len(),
+            protected.join(", ")
+        );
+    }
+
+    if packages.is_empty() {
+        log::info!("All packages are excluded - no cleanup needed");
+        return Ok(());
+    }
+
+    let command = format!("scoop cleanup {}", packages.join(" "));
     run_cleanup_command(
         window,
-        "scoop cleanup --all",
+        &command,
         "Force Cleanup All App Versions",
         "cleanup-force",
     )
@@ -120,7 +219,7 @@ pub async fn cleanup_outdated_cache<R: Runtime>(
     window: Window,
     app: AppHandle<R>,
     state: State<'_, AppState>,
-) -> Result<(), String> {
+) -> Result<(), AppError> {
     log::info!("Running version-aware cleanup of outdated app caches");
 
     // Get all installed packages to identify versioned installs
@@ -133,7 +232,10 @@ pub async fn cleanup_outdated_cache<R: Runtime>(
         },
         Err(e) => {
             log::error!("Failed to retrieve installed packages for cache cleanup: {}", e);
-            return Err(format!("Failed to retrieve installed packages: {}", e));
+            return Err(AppError::OperationFailed(format!(
+                "Failed to retrieve installed packages: {}",
+                e
+            )));
         }
     };
 
@@ -155,4 +257,130 @@ pub async fn cleanup_outdated_cache<R: Runtime>(
 
     log::info!("Running cache cleanup for packages: {}", packages_str);
     run_cleanup_command(window, &command, "Cleanup Outdated App Caches", "cleanup-cache").await
-}
\ No newline at end of file
+}
+
+/// Old versions of an installed app that a cleanup would remove.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct OldVersions {
+    pub app: String,
+    /// Non-current version directories, oldest first.
+    pub versions: Vec<String>,
+    pub current: String,
+}
+
+/// Resolves the version the `current` junction of an app points to.
+///
+/// Falls back to the version in `current/manifest.json` if the link cannot be read.
+fn current_version(package_path: &Path) -> Option<String> {
+    let current_path = package_path.join("current");
+    if let Ok(target) = std::fs::read_link(&current_path) {
+        if let Some(name) = target.file_name().and_then(|n| n.to_str()) {
+            return Some(name.to_string());
+        }
+    }
+
+    let manifest = std::fs::read_to_string(current_path.join("manifest.json")).ok()?;
+    serde_json::from_str::<serde_json::Value>(&manifest)
+        .ok()?
+        .get("version")?
+        .as_str()
+        .map(str::to_string)
+}
+
+/// Lists the version directories of an app other than `current` and the version it points to.
+fn old_version_dirs(package_path: &Path, current: &str) -> Vec<String> {
+    let mut versions: Vec<String> = std::fs::read_dir(package_path)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter(|entry| entry.path().is_dir())
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .filter(|name| !name.eq_ignore_ascii_case("current") && name != current)
+                .collect()
+        })
+        .unwrap_or_default();
+    versions.sort_by(|a, b| compare_versions(a, b));
+    versions
+}
+
+/// Lists the old versions of every installed app, i.e. what `cleanup_all_apps` would remove.
+///
+/// Apps whose current version cannot be determined are skipped.
+#[tauri::command]
+pub fn list_old_versions(state: State<'_, AppState>) -> Result<Vec<OldVersions>, String> {
+    let apps_path = state.scoop_path().join("apps");
+    let entries = std::fs::read_dir(&apps_path)
+        .map_err(|e| format!("Failed to read apps directory: {}", e))?;
+
+    let mut result = Vec::new();
+    for entry in entries.flatten() {
+        let package_path = entry.path();
+        if !package_path.is_dir() {
+            continue;
+        }
+        let Some(current) = current_version(&package_path) else {
+            continue;
+        };
+        let versions = old_version_dirs(&package_path, &current);
+        if !versions.is_empty() {
+            result.push(OldVersions {
+                app: entry.file_name().to_string_lossy().to_string(),
+                versions,
+                current,
+            });
+        }
+    }
+    result.sort_by(|a, b| a.app.to_lowercase().cmp(&b.app.to_lowercase()));
+    Ok(result)
+}
+
+/// Deletes a single old version directory of an app.
+///
+/// Refuses to touch the `current` junction or the version it points to.
+#[tauri::command]
+pub async fn remove_version(
+    state: State<'_, AppState>,
+    app: String,
+    version: String,
+) -> Result<(), AppError> {
+    let is_plain_name =
+        |s: &str| !s.is_empty() && s != "." && s != ".." && !s.contains(['/', '\\']);
+    if !is_plain_name(&app) || !is_plain_name(&version) {
+        return Err(AppError::InvalidInput(format!(
+            "Invalid app or version: {} {}",
+            app, version
+        )));
+    }
+
+    let package_path = state.scoop_path().join("apps").join(&app);
+    let Some(current) = current_version(&package_path) else {
+        return Err(AppError::InvalidInput(format!(
+            "Cannot determine the current version of {}",
+            app
+        )));
+    };
+    if version.eq_ignore_ascii_case("current") || current == version {
+        return Err(AppError::InvalidInput(format!(
+            "Refusing to remove the current version of {}",
+            app
+        )));
+    }
+
+    let version_dir = package_path.join(&version);
+    if !version_dir.is_dir() {
+        return Err(AppError::InvalidInput(format!(
+            "Version {} of {} is not installed",
+            version, app
+        )));
+    }
+
+    log::info!("Removing old version directory: {}", version_dir.display());
+    if !crate::commands::debug::safe_remove_dir(&version_dir) {
+        return Err(AppError::OperationFailed(format!(
+            "Failed to remove {}",
+            version_dir.display()
+        )));
+    }
+    invalidate_disk_usage(&state).await;
+    Ok(())
+}
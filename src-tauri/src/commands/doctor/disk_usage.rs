@@ -0,0 +1,312 @@
+//! Commands for measuring how much disk space Scoop uses.
+use crate::commands::installed::get_installed_packages_full;
+use crate::state::{AppState, DiskUsageCache};
+use rayon::prelude::*;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Runtime, State};
+
+/// Disk usage of a single app directory under `apps/`.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AppDiskUsage {
+    pub name: String,
+    /// Installed version, if the app is known to the installed packages scan.
+    pub version: Option<String>,
+    /// Total size of all version directories of this app.
+    pub size_bytes: u64,
+    /// Number of version directories (excluding the `current` link).
+    pub version_count: usize,
+}
+
+/// Disk usage of the Scoop installation, with apps sorted by size descending.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DiskUsageReport {
+    pub apps: Vec<AppDiskUsage>,
+    pub apps_total_bytes: u64,
+    pub cache_bytes: u64,
+    pub total_bytes: u64,
+}
+
+/// Recursively sums file sizes below `path`.
+///
+/// Symlinks and junctions are not followed: the `current` junction points at one of
+/// the version directories, which are already counted on their own.
+pub(crate) fn dir_size(path: &Path) -> u64 {
+    let entries = match fs::read_dir(path) {
+        Ok(entries) => entries,
+        Err(_) => return 0,
+    };
+
+    entries
+        .filter_map(Result::ok)
+        .map(|entry| match fs::symlink_metadata(entry.path()) {
+            Ok(meta) if meta.file_type().is_symlink() => 0,
+            Ok(meta) if meta.is_dir() => dir_size(&entry.path()),
+            Ok(meta) => meta.len(),
+            Err(_) => 0,
+        })
+        .sum()
+}
+
+/// Measures a single app directory.
+fn measure_app(app_dir: &Path, versions: &HashMap<String, String>) -> Option<AppDiskUsage> {
+    let name = app_dir.file_name()?.to_str()?.to_string();
+
+    let version_count = fs::read_dir(app_dir)
+        .map(|entries| {
+            entries
+                .filter_map(Result::ok)
+                .filter(|e| !e.file_name().eq_ignore_ascii_case("current"))
+                .filter(|e| e.path().is_dir())
+                .count()
+        })
+        .unwrap_or(0);
+
+    Some(AppDiskUsage {
+        version: versions.get(&name.to_lowercase()).cloned(),
+        name,
+        size_bytes: dir_size(app_dir),
+        version_count,
+    })
+}
+
+/// Walks the Scoop `apps` and `cache` directories and reports their sizes.
+pub(crate) async fn compute_disk_usage<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+) -> Result<DiskUsageReport, String> {
+    let scoop_path = state.scoop_path();
+    let apps_path = scoop_path.join("apps");
+    let cache_path = scoop_path.join("cache");
+
+    let versions: HashMap<String, String> = get_installed_packages_full(app, state)
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .map(|pkg| (pkg.name.to_lowercase(), pkg.version))
+        .collect();
+
+    tokio::task::spawn_blocking(move || {
+        let app_dirs: Vec<PathBuf> = match fs::read_dir(&apps_path) {
+            Ok(entries) => entries
+                .filter_map(Result::ok)
+                .map(|e| e.path())
+                .filter(|p| p.is_dir())
+                .collect(),
+            Err(e) => {
+                log::warn!("Failed to read apps directory {}: {}", apps_path.display(), e);
+                Vec::new()
+            }
+        };
+
+        let mut apps: Vec<AppDiskUsage> = app_dirs
+            .par_iter()
+            .filter_map(|dir| measure_app(dir, &versions))
+            .collect();
+        apps.sort_by(|a, b| b.size_bytes.cmp(&a.size_bytes));
+
+        let apps_total_bytes = apps.iter().map(|a| a.size_bytes).sum();
+        let cache_bytes = dir_size(&cache_path);
+
+        DiskUsageReport {
+            apps,
+            apps_total_bytes,
+            cache_bytes,
+            total_bytes: apps_total_bytes + cache_bytes,
+        }
+    })
+    .await
+    .map_err(|e| format!("Failed to compute disk usage: {}", e))
+}
+
+/// Drops the cached `get_disk_usage` result.
+///
+/// Needed after the cache or old version directories change, since the installed
+/// packages fingerprint only covers the current installs.
+pub(crate) async fn invalidate_disk_usage(state: &AppState) {
+    *state.disk_usage.lock().await = None;
+}
+
+/// Reports disk usage per installed app plus the total size of the download cache.
+///
+/// The result is cached against the installed packages fingerprint, so repeated calls
+/// only walk the directories again after something changed. Pass `force` to bypass
+/// the cache when the user explicitly refreshes.
+#[tauri::command]
+pub async fn get_disk_usage<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+    force: Option<bool>,
+) -> Result<DiskUsageReport, String> {
+    // Refreshes the installed packages cache, and with it the current fingerprint
+    get_installed_packages_full(app.clone(), state.clone()).await?;
+    let fingerprint = state
+        .installed_packages
+        .lock()
+        .await
+        .as_ref()
+        .map(|cache| cache.fingerprint.clone());
+
+    if !force.unwrap_or(false) {
+        if let (Some(cache), Some(fingerprint)) =
+            (state.disk_usage.lock().await.as_ref(), fingerprint.as_ref())
+        {
+            if cache.fingerprint == *fingerprint {
+                log::debug!("Returning cached disk usage");
+                return Ok(cache.report.clone());
+            }
+        }
+    }
+
+    log::info!("Computing scoop disk usage");
+    let report = compute_disk_usage(app, state.clone()).await?;
+    if let Some(fingerprint) = fingerprint {
+        *state.disk_usage.lock().await = Some(DiskUsageCache {
+            fingerprint,
+            report: report.clone(),
+        });
+    }
+    log::info!(
+        "Disk usage: {} apps, {} bytes in apps, {} bytes in cache",
+        report.apps.len(),
+        report.apps_total_bytes,
+        report.cache_bytes
+    );
+    Ok(report)
+}
+
+/// A single cleanup action and the space it would free.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CleanupAction {
+    /// Name of the command that performs the action, e.g. `cleanup_all_apps`.
+    pub command: String,
+    pub description: String,
+    pub reclaimable_bytes: u64,
+    /// Number of version directories or cache files affected.
+    pub item_count: usize,
+}
+
+/// Estimate of how much space the available cleanup actions would free.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CleanupRecommendation {
+    /// Current total size of the `apps` and `cache` directories.
+    pub total_bytes: u64,
+    pub reclaimable_bytes: u64,
+    /// Actions that would free space, largest first.
+    pub actions: Vec<CleanupAction>,
+}
+
+/// Sums the old version directories of an app, i.e. everything except `current`
+/// and the installed version. Returns `(bytes, directory count)`.
+fn old_versions_size(app_dir: &Path, installed_version: &str) -> (u64, usize) {
+    let entries = match fs::read_dir(app_dir) {
+        Ok(entries) => entries,
+        Err(_) => return (0, 0),
+    };
+
+    entries
+        .filter_map(Result::ok)
+        .filter(|e| {
+            let name = e.file_name();
+            !name.eq_ignore_ascii_case("current") && name.to_string_lossy() != installed_version
+        })
+        .filter(|e| {
+            fs::symlink_metadata(e.path())
+                .map(|m| m.is_dir() && !m.file_type().is_symlink())
+                .unwrap_or(false)
+        })
+        .fold((0, 0), |(bytes, count), e| (bytes + dir_size(&e.path()), count + 1))
+}
+
+/// Estimates how much space `cleanup_all_apps` and `cleanup_outdated_cache` would
+/// reclaim, without deleting anything. Versioned installs are excluded, as they are
+/// by the cleanup commands themselves.
+#[tauri::command]
+pub async fn recommend_cleanup<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+) -> Result<CleanupRecommendation, String> {
+    log::info!("Computing cleanup recommendation");
+
+    let scoop_path = state.scoop_path();
+    let installed = get_installed_packages_full(app.clone(), state.clone()).await?;
+    let usage = compute_disk_usage(app, state).await?;
+
+    let (old_versions, cache) = tokio::task::spawn_blocking(move || {
+        let regular: Vec<_> = installed.iter().filter(|p| !p.is_versioned_install).collect();
+        let versioned: std::collections::HashSet<String> = installed
+            .iter()
+            .filter(|p| p.is_versioned_install)
+            .map(|p| p.name.to_lowercase())
+            .collect();
+
+        let old_versions = regular
+            .par_iter()
+            .map(|p| old_versions_size(&scoop_path.join("apps").join(&p.name), &p.version))
+            .reduce(|| (0, 0), |a, b| (a.0 + b.0, a.1 + b.1));
+
+        // Cache files are named `name#version#hash.ext`; anything not matching the
+        // installed version of a regular package is outdated.
+        let current: HashMap<String, &str> = regular
+            .iter()
+            .map(|p| (p.name.to_lowercase(), p.version.as_str()))
+            .collect();
+        let cache = fs::read_dir(scoop_path.join("cache"))
+            .map(|entries| {
+                entries
+                    .filter_map(Result::ok)
+                    .filter_map(|e| {
+                        let file_name = e.file_name().to_string_lossy().to_string();
+                        let mut parts = file_name.split('#');
+                        let name = parts.next()?.to_lowercase();
+                        let version = parts.next()?;
+                        if versioned.contains(&name) || current.get(&name) == Some(&version) {
+                            return None;
+                        }
+                        e.metadata().ok().filter(|m| m.is_file()).map(|m| m.len())
+                    })
+                    .fold((0, 0), |(bytes, count), len| (bytes + len, count + 1))
+            })
+            .unwrap_or((0, 0));
+
+        (old_versions, cache)
+    })
+    .await
+    .map_err(|e| format!("Failed to compute cleanup recommendation: {}", e))?;
+
+    let mut actions = vec![
+        CleanupAction {
+            command: "cleanup_all_apps".to_string(),
+            description: "Remove old versions of installed apps".to_string(),
+            reclaimable_bytes: old_versions.0,
+            item_count: old_versions.1,
+        },
+        CleanupAction {
+            command: "cleanup_outdated_cache".to_string(),
+            description: "Remove cached downloads of outdated versions".to_string(),
+            reclaimable_bytes: cache.0,
+            item_count: cache.1,
+        },
+    ];
+    actions.retain(|a| a.item_count > 0);
+    actions.sort_by(|a, b| b.reclaimable_bytes.cmp(&a.reclaimable_bytes));
+
+    let reclaimable_bytes = actions.iter().map(|a| a.reclaimable_bytes).sum();
+    log::info!(
+        "Cleanup could reclaim {} of {} bytes",
+        reclaimable_bytes,
+        usage.total_bytes
+    );
+
+    Ok(CleanupRecommendation {
+        total_bytes: usage.total_bytes,
+        reclaimable_bytes,
+        actions,
+    })
+}
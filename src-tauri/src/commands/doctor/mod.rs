@@ -1,5 +1,7 @@
 pub mod cache;
 pub mod checkup;
 pub mod cleanup;
+pub mod disk_usage;
+pub mod report;
 pub mod shim;
 pub mod windows_checks;
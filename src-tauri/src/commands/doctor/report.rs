@@ -0,0 +1,188 @@
+//! Aggregated health check combining `scoop checkup` with Rscoop's own doctor checks.
+use crate::commands::doctor::{cache, checkup, shim};
+use crate::commands::scoop::{self, ScoopOp};
+use crate::state::AppState;
+use serde::Serialize;
+use tauri::{Manager, State, Window};
+
+/// How serious a doctor finding is.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+/// A single finding of `run_doctor`.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DoctorIssue {
+    /// Stable identifier of the check, e.g. "danglingShims".
+    pub id: String,
+    pub severity: Severity,
+    pub message: String,
+    /// Affected apps, shims or cache files.
+    pub items: Vec<String>,
+    pub suggestion: Option<String>,
+}
+
+/// Result of `run_doctor`.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DoctorReport {
+    /// Whether `scoop checkup` itself completed without reporting errors.
+    pub scoop_checkup_passed: bool,
+    pub issues: Vec<DoctorIssue>,
+    /// Highest severity among `issues`, or `None` when everything is healthy.
+    pub worst_severity: Option<Severity>,
+}
+
+impl DoctorIssue {
+    fn new(id: &str, severity: Severity, message: String, items: Vec<String>) -> Self {
+        Self {
+            id: id.to_string(),
+            severity,
+            message,
+            items,
+            suggestion: None,
+        }
+    }
+
+    fn with_suggestion(mut self, suggestion: &str) -> Self {
+        self.suggestion = Some(suggestion.to_string());
+        self
+    }
+}
+
+/// Runs `scoop checkup` (streaming its output) and Rscoop's own checks, and
+/// combines everything into one report.
+///
+/// Individual checks that fail to run are logged and skipped, so a single broken
+/// check never hides the results of the others.
+#[tauri::command]
+pub async fn run_doctor(
+    window: Window,
+    state: State<'_, AppState>,
+) -> Result<DoctorReport, String> {
+    log::info!("Running doctor health check");
+    let app = window.app_handle().clone();
+    let scoop_path = state.scoop_path();
+    let mut issues = Vec::new();
+
+    let scoop_checkup_passed =
+        match scoop::execute_scoop(window.clone(), ScoopOp::Checkup, None, None, None, false).await
+        {
+            Ok(()) => true,
+            Err(e) => {
+                log::warn!("scoop checkup reported problems: {}", e);
+                issues.push(
+                    DoctorIssue::new("scoopCheckup", Severity::Warning, e, Vec::new())
+                        .with_suggestion("Review the scoop checkup output for details."),
+                );
+                false
+            }
+        };
+
+    let git = checkup::check_git_installed().await;
+    if !git.status {
+        issues.push(DoctorIssue {
+            id: "gitMissing".to_string(),
+            severity: Severity::Error,
+            message: "Git is not installed".to_string(),
+            items: vec!["git".to_string()],
+            suggestion: git.suggestion,
+        });
+    }
+
+    if !scoop_path
+        .join("apps")
+        .join("7zip")
+        .join("current")
+        .exists()
+    {
+        issues.push(
+            DoctorIssue::new(
+                "sevenZipMissing",
+                Severity::Warning,
+                "7zip is not installed; many packages cannot be extracted".to_string(),
+                vec!["7zip".to_string()],
+            )
+            .with_suggestion("scoop install 7zip"),
+        );
+    }
+
+    let (drive_type, _) = crate::commands::status::volume_info(&scoop_path);
+    if let Some(drive_type @ ("network" | "removable")) = drive_type.as_deref() {
+        issues.push(
+            DoctorIssue::new(
+                "slowDrive",
+                Severity::Warning,
+                format!(
+                    "Scoop is installed on a {} drive, which makes installs and cleanups slow",
+                    drive_type
+                ),
+                vec![scoop_path.display().to_string()],
+            )
+            .with_suggestion("Move Scoop to a local fixed drive."),
+        );
+    }
+
+    match shim::dangling_shim_names(&scoop_path) {
+        Ok(shims) if !shims.is_empty() => issues.push(
+            DoctorIssue::new(
+                "danglingShims",
+                Severity::Warning,
+                format!(
+                    "{} shims point to apps that are no longer installed",
+                    shims.len()
+                ),
+                shims,
+            )
+            .with_suggestion("Remove them from the shims page."),
+        ),
+        Ok(_) => {}
+        Err(e) => log::warn!("Dangling shim check failed: {}", e),
+    }
+
+    match crate::commands::dependencies::find_orphaned_packages(app.clone(), state.clone()).await {
+        Ok(orphans) if !orphans.is_empty() => issues.push(
+            DoctorIssue::new(
+                "orphanedDependencies",
+                Severity::Info,
+                format!("{} dependency packages are no longer needed", orphans.len()),
+                orphans,
+            )
+            .with_suggestion("Uninstall them if you do not use them directly."),
+        ),
+        Ok(_) => {}
+        Err(e) => log::warn!("Orphaned dependency check failed: {}", e),
+    }
+
+    match cache::verify_cache(app, state, None).await {
+        Ok(entries) if !entries.is_empty() => issues.push(
+            DoctorIssue::new(
+                "corruptCache",
+                Severity::Warning,
+                format!("{} cached downloads are empty or corrupt", entries.len()),
+                entries.into_iter().map(|entry| entry.file_name).collect(),
+            )
+            .with_suggestion("Clear the affected cache entries before reinstalling."),
+        ),
+        Ok(_) => {}
+        Err(e) => log::warn!("Cache verification failed: {}", e),
+    }
+
+    let worst_severity = issues.iter().map(|issue| issue.severity).max();
+    log::info!(
+        "Doctor finished with {} issues (worst: {:?})",
+        issues.len(),
+        worst_severity
+    );
+
+    Ok(DoctorReport {
+        scoop_checkup_passed,
+        issues,
+        worst_severity,
+    })
+}
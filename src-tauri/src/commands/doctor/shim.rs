@@ -248,3 +248,208 @@ pub fn add_shim(state: State<'_, AppState>, args: AddShimArgs) -> Result<(), Str
 
     Ok(())
 }
+
+/// Summary of a `repair_shims` run.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ShimRepairReport {
+    /// Number of apps that were reset successfully.
+    pub apps_reset: usize,
+    /// Apps for which `scoop reset` failed.
+    pub failed_apps: Vec<String>,
+    /// Number of shims that now point at the successfully reset apps.
+    pub shims_regenerated: usize,
+}
+
+/// Regenerates the shims of all installed apps by running `scoop reset` on each,
+/// streaming the output of every reset to the frontend.
+#[tauri::command]
+pub async fn repair_shims(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+) -> Result<ShimRepairReport, String> {
+    let app = tauri::Manager::app_handle(&window).clone();
+    let installed =
+        crate::commands::installed::get_installed_packages_full(app, state.clone()).await?;
+    log::info!("Repairing shims for {} installed apps", installed.len());
+
+    let mut reset_apps = HashSet::new();
+    let mut failed_apps = Vec::new();
+    for package in installed.iter().filter(|p| p.name != "scoop") {
+        match crate::commands::uninstall::execute_package_operation(
+            window.clone(),
+            crate::commands::scoop::ScoopOp::Reset,
+            &package.name,
+            None,
+        )
+        .await
+        {
+            Ok(()) => {
+                reset_apps.insert(package.name.to_lowercase());
+            }
+            Err(e) => {
+                log::warn!("Failed to reset '{}': {}", package.name, e);
+                failed_apps.push(package.name.clone());
+            }
+        }
+    }
+
+    crate::commands::installed::invalidate_installed_cache(state.clone()).await;
+
+    let shims_regenerated = process_shim_dir(&state.scoop_path().join("shims"), false)?
+        .iter()
+        .filter(|shim| reset_apps.contains(&shim.source.to_lowercase()))
+        .count();
+
+    log::info!(
+        "Shim repair finished: {} apps reset, {} failed, {} shims regenerated",
+        reset_apps.len(),
+        failed_apps.len(),
+        shims_regenerated
+    );
+
+    Ok(ShimRepairReport {
+        apps_reset: reset_apps.len(),
+        failed_apps,
+        shims_regenerated,
+    })
+}
+
+/// Returns the app a local shim points into, if it points into `apps/` at all.
+///
+/// `.shim` files are read for their `path`; script shims (`.ps1`, `.cmd`, extensionless)
+/// embed the target path in their contents.
+fn shim_target_app(path: &Path) -> Option<String> {
+    let content = fs::read_to_string(path).ok()?;
+    let target = if path.extension().and_then(|e| e.to_str()) == Some("shim") {
+        parse_shim_file_content(&content).0?
+    } else {
+        content
+    };
+    SOURCE_RE
+        .captures(&target)
+        .and_then(|c| c.get(1))
+        .map(|m| m.as_str().to_string())
+}
+
+/// Lists the names of local shims whose target app is no longer installed.
+pub(crate) fn dangling_shim_names(scoop_path: &Path) -> Result<Vec<String>, String> {
+    let shims_dir = scoop_path.join("shims");
+    let apps_dir = scoop_path.join("apps");
+    if !shims_dir.is_dir() {
+        return Ok(vec![]);
+    }
+
+    let entries =
+        fs::read_dir(&shims_dir).map_err(|e| format!("Failed to read shim dir: {}", e))?;
+
+    let mut names: Vec<String> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .filter(|path| {
+            // The .exe is a copy of shim.exe; its target lives in the sibling .shim file.
+            !matches!(
+                path.extension().and_then(|e| e.to_str()),
+                Some("exe") | Some("shimmed")
+            )
+        })
+        .filter_map(|path| {
+            let app = shim_target_app(&path)?;
+            if apps_dir.join(&app).is_dir() {
+                return None;
+            }
+            log::debug!("Shim {:?} points to missing app '{}'", path, app);
+            path.file_stem()
+                .and_then(|n| n.to_str())
+                .map(|n| n.to_string())
+        })
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+
+    names.sort_by_key(|name| name.to_lowercase());
+    Ok(names)
+}
+
+/// Lists local shims that point to apps no longer present in the `apps` directory.
+#[tauri::command]
+pub fn find_dangling_shims(state: State<'_, AppState>) -> Result<Vec<String>, String> {
+    let names = dangling_shim_names(&state.scoop_path())?;
+    log::info!("Found {} dangling shims", names.len());
+    Ok(names)
+}
+
+/// Extensions of the files Scoop creates for a single shim, next to the extensionless
+/// shell script. `exe.shimmed` is the renamed `.exe` of a hidden shim.
+const SHIM_FILE_EXTENSIONS: [&str; 5] = ["exe", "shim", "cmd", "ps1", "exe.shimmed"];
+
+/// Returns true if `file_name` is one of the files Scoop creates for the shim `name`.
+fn is_shim_file_of(file_name: &str, name: &str) -> bool {
+    file_name == name
+        || file_name
+            .strip_prefix(name)
+            .and_then(|rest| rest.strip_prefix('.'))
+            .map_or(false, |ext| SHIM_FILE_EXTENSIONS.contains(&ext))
+}
+
+/// Removes all files of the dangling shims reported by `find_dangling_shims`.
+///
+/// The UI is expected to confirm with the user before calling this. Each removed
+/// file is reported as an output line; returns the number of shims removed.
+#[tauri::command]
+pub fn remove_dangling_shims(
+    window: tauri::Window,
+    state: State<'_, AppState>,
+) -> Result<usize, String> {
+    use crate::commands::powershell::{StreamOutput, EVENT_OUTPUT};
+    use tauri::Emitter;
+
+    let scoop_path = state.scoop_path();
+    let shims_dir = scoop_path.join("shims");
+    let names = dangling_shim_names(&scoop_path)?;
+    log::info!("Removing {} dangling shims", names.len());
+
+    let entries: Vec<PathBuf> = fs::read_dir(&shims_dir)
+        .map_err(|e| format!("Failed to read shim dir: {}", e))?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .collect();
+
+    let mut removed = 0;
+    for name in &names {
+        let mut removed_any = false;
+        for path in entries.iter().filter(|path| {
+            path.file_name()
+                .and_then(|n| n.to_str())
+                .map_or(false, |n| is_shim_file_of(n, name))
+        }) {
+            match fs::remove_file(path) {
+                Ok(()) => {
+                    removed_any = true;
+                    let _ = window.emit(
+                        EVENT_OUTPUT,
+                        StreamOutput::new(format!("Removed {}", path.display()), "stdout", None),
+                    );
+                }
+                Err(e) => {
+                    log::error!("Failed to remove '{:?}': {}", path, e);
+                    let _ = window.emit(
+                        EVENT_OUTPUT,
+                        StreamOutput::new(
+                            format!("Failed to remove {}: {}", path.display(), e),
+                            "stderr",
+                            None,
+                        ),
+                    );
+                }
+            }
+        }
+        if removed_any {
+            removed += 1;
+        }
+    }
+
+    log::info!("Removed {} dangling shims", removed);
+    Ok(removed)
+}
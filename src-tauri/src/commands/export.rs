@@ -0,0 +1,357 @@
+//! Commands for exporting the list of installed packages to a JSON file and importing it again.
+use crate::commands::installed::{get_installed_packages_full, invalidate_installed_cache};
+use crate::commands::powershell::{CommandResult, StreamOutput, EVENT_FINISHED, EVENT_OUTPUT};
+use crate::commands::scoop::{validate_version, ScoopOp};
+use crate::commands::search::invalidate_manifest_cache;
+use crate::commands::uninstall::{
+    max_concurrent_operations, run_batch_operation, BatchPackageResult,
+};
+use crate::state::AppState;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use tauri::{AppHandle, Emitter, Manager, Runtime, State, Window};
+
+/// Operation id used for the `operation-output` lines emitted during an export.
+const EXPORT_OPERATION_ID: &str = "export-installed";
+
+/// A single package entry in an export file.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedPackage {
+    pub name: String,
+    pub version: String,
+    pub source: String,
+    /// The version the install is pinned to (`scoop install name@version`), if any.
+    #[serde(default)]
+    pub pinned_version: Option<String>,
+    #[serde(default)]
+    pub is_held: bool,
+}
+
+/// The on-disk format written by `export_installed`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InstalledExport {
+    /// Unix timestamp (seconds) when the export was written.
+    pub exported_at: u64,
+    pub packages: Vec<ExportedPackage>,
+}
+
+/// Package and bucket names accepted by `import_installed`.
+static NAME_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[A-Za-z0-9._-]+$").unwrap());
+
+/// A package entry read by `import_installed`. Only the name is required.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportedPackage {
+    pub name: String,
+    /// The bucket the package was installed from.
+    #[serde(default)]
+    pub source: Option<String>,
+    #[serde(default)]
+    pub version: Option<String>,
+    /// Installed as `name@version` when present.
+    #[serde(default)]
+    pub pinned_version: Option<String>,
+}
+
+impl ImportedPackage {
+    /// The argument passed to `scoop install`.
+    fn install_target(&self) -> String {
+        let mut target = match &self.source {
+            Some(bucket) => format!("{}/{}", bucket, self.name),
+            None => self.name.clone(),
+        };
+        if let Some(version) = &self.pinned_version {
+            target.push('@');
+            target.push_str(version);
+        }
+        target
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct ImportFile {
+    packages: Vec<ImportedPackage>,
+}
+
+/// Parses and validates an export file, rejecting the whole file if any entry is invalid.
+fn parse_import(content: &str) -> Result<Vec<ImportedPackage>, String> {
+    let file: ImportFile =
+        serde_json::from_str(content).map_err(|e| format!("Invalid import file: {}", e))?;
+
+    for (index, package) in file.packages.iter().enumerate() {
+        if !NAME_RE.is_match(&package.name) {
+            return Err(format!(
+                "Invalid package name in entry {}: '{}'",
+                index + 1,
+                package.name
+            ));
+        }
+        if let Some(bucket) = package.source.as_deref() {
+            if !NAME_RE.is_match(bucket) {
+                return Err(format!(
+                    "Invalid bucket for '{}' in entry {}: '{}'",
+                    package.name,
+                    index + 1,
+                    bucket
+                ));
+            }
+        }
+        for version in [&package.version, &package.pinned_version]
+            .into_iter()
+            .flatten()
+        {
+            validate_version(version)
+                .map_err(|e| format!("Entry {} ('{}'): {}", index + 1, package.name, e))?;
+        }
+    }
+
+    Ok(file.packages)
+}
+
+/// Installs the packages listed in an export file that are not installed yet.
+///
+/// The file is validated up front and rejected as a whole if any entry is invalid, so
+/// nothing is installed from a malformed file.
+#[tauri::command]
+pub async fn import_installed(
+    window: Window,
+    app: AppHandle,
+    state: State<'_, AppState>,
+    path: String,
+) -> Result<Vec<BatchPackageResult>, String> {
+    log::info!("Importing installed packages from {}", path);
+
+    let content =
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let packages = parse_import(&content)?;
+
+    let installed: std::collections::HashSet<String> =
+        get_installed_packages_full(app.clone(), state.clone())
+            .await?
+            .into_iter()
+            .map(|p| p.name.to_lowercase())
+            .collect();
+    let targets: Vec<String> = packages
+        .iter()
+        .filter(|p| !installed.contains(&p.name.to_lowercase()))
+        .map(ImportedPackage::install_target)
+        .collect();
+
+    log::info!(
+        "Import: {} packages listed, {} to install",
+        packages.len(),
+        targets.len()
+    );
+    if targets.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let max_concurrent = max_concurrent_operations(&app);
+    let results = run_batch_operation(&window, ScoopOp::Install, targets, max_concurrent).await;
+
+    invalidate_manifest_cache().await;
+    invalidate_installed_cache(state).await;
+    Ok(results)
+}
+
+/// A package whose version differs between an export and the current installation.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VersionChange {
+    pub name: String,
+    pub old_version: String,
+    pub new_version: String,
+}
+
+/// Differences between a previous export and the currently installed packages.
+#[derive(Serialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct InstalledDiff {
+    pub added: Vec<ExportedPackage>,
+    pub removed: Vec<ExportedPackage>,
+    pub changed: Vec<VersionChange>,
+}
+
+/// Exports all installed packages to `path`, streaming one output line per package.
+///
+/// Returns the number of exported packages.
+#[tauri::command]
+pub async fn export_installed(
+    window: Window,
+    state: State<'_, AppState>,
+    path: String,
+) -> Result<usize, String> {
+    log::info!("Exporting installed packages to {}", path);
+
+    let app = window.app_handle().clone();
+    let installed = get_installed_packages_full(app, state).await?;
+    let operation_id = Some(EXPORT_OPERATION_ID.to_string());
+
+    let mut packages = Vec::with_capacity(installed.len());
+    for package in installed {
+        let pinned_version = if package.is_versioned_install {
+            Some(package.version.clone())
+        } else {
+            None
+        };
+
+        let line = match &pinned_version {
+            Some(version) => format!(
+                "{} {} ({}, pinned to {})",
+                package.name, package.version, package.source, version
+            ),
+            None => format!("{} {} ({})", package.name, package.version, package.source),
+        };
+        if let Err(e) = window.emit(
+            EVENT_OUTPUT,
+            StreamOutput::new(line, "stdout", operation_id.clone()),
+        ) {
+            log::error!("Failed to emit export output line: {}", e);
+        }
+
+        packages.push(ExportedPackage {
+            name: package.name,
+            version: package.version,
+            source: package.source,
+            pinned_version,
+            is_held: package.is_held,
+        });
+    }
+
+    let count = packages.len();
+    let export = InstalledExport {
+        exported_at: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+        packages,
+    };
+
+    let result = serde_json::to_string_pretty(&export)
+        .map_err(|e| format!("Failed to serialize export: {}", e))
+        .and_then(|json| {
+            fs::write(&path, json).map_err(|e| format!("Failed to write export to {}: {}", path, e))
+        });
+
+    let message = match &result {
+        Ok(()) => format!("Exported {} packages to {}", count, path),
+        Err(e) => e.clone(),
+    };
+    if let Err(e) = window.emit(
+        EVENT_FINISHED,
+        CommandResult {
+            success: result.is_ok(),
+            message,
+            operation_id,
+        },
+    ) {
+        log::error!("Failed to emit export finished event: {}", e);
+    }
+
+    result.map(|_| count)
+}
+
+/// Compares the export at `old_path` with the currently installed packages.
+#[tauri::command]
+pub async fn diff_installed<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+    old_path: String,
+) -> Result<InstalledDiff, String> {
+    log::info!("Diffing installed packages against {}", old_path);
+
+    let content = fs::read_to_string(&old_path)
+        .map_err(|e| format!("Failed to read export {}: {}", old_path, e))?;
+    let old_export: InstalledExport = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse export {}: {}", old_path, e))?;
+
+    let current = get_installed_packages_full(app, state).await?;
+    let mut old_by_name: HashMap<String, ExportedPackage> = old_export
+        .packages
+        .into_iter()
+        .map(|p| (p.name.to_lowercase(), p))
+        .collect();
+
+    let mut diff = InstalledDiff::default();
+    for package in current {
+        match old_by_name.remove(&package.name.to_lowercase()) {
+            Some(old) if old.version != package.version => diff.changed.push(VersionChange {
+                name: package.name,
+                old_version: old.version,
+                new_version: package.version,
+            }),
+            Some(_) => {}
+            None => diff.added.push(ExportedPackage {
+                pinned_version: package
+                    .is_versioned_install
+                    .then(|| package.version.clone()),
+                name: package.name,
+                version: package.version,
+                source: package.source,
+                is_held: package.is_held,
+            }),
+        }
+    }
+    diff.removed = old_by_name.into_values().collect();
+
+    diff.added.sort_by(|a, b| a.name.cmp(&b.name));
+    diff.removed.sort_by(|a, b| a.name.cmp(&b.name));
+    diff.changed.sort_by(|a, b| a.name.cmp(&b.name));
+
+    log::info!(
+        "Installed diff: {} added, {} removed, {} changed",
+        diff.added.len(),
+        diff.removed.len(),
+        diff.changed.len()
+    );
+    Ok(diff)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_valid_import_file() {
+        let content = r#"{
+            "exportedAt": 1700000000,
+            "packages": [
+                {"name": "git", "version": "2.45.0", "source": "main", "isHeld": false},
+                {"name": "python310", "source": "versions", "pinnedVersion": "3.10.11"},
+                {"name": "7zip"}
+            ]
+        }"#;
+
+        let packages = parse_import(content).unwrap();
+        assert_eq!(packages.len(), 3);
+        assert_eq!(packages[0].install_target(), "main/git");
+        assert_eq!(packages[1].install_target(), "versions/python310@3.10.11");
+        assert_eq!(packages[2].install_target(), "7zip");
+    }
+
+    #[test]
+    fn rejects_injection_in_name() {
+        let content = r#"{
+            "packages": [
+                {"name": "git"},
+                {"name": "git; Remove-Item -Recurse C:\"}
+            ]
+        }"#;
+
+        let err = parse_import(content).unwrap_err();
+        assert!(err.contains("Invalid package name in entry 2"), "{}", err);
+    }
+
+    #[test]
+    fn rejects_truncated_file() {
+        let content = r#"{"packages": [{"name": "git"}, {"name": "7z"#;
+
+        let err = parse_import(content).unwrap_err();
+        assert!(err.starts_with("Invalid import file"), "{}", err);
+    }
+}
@@ -4,7 +4,10 @@ use rayon::prelude::*;
 use serde_json::Value;
 use std::fs;
 use std::path::{Path, PathBuf};
-use tauri::{AppHandle, Runtime, State};
+use tauri::{AppHandle, Runtime, State, Window};
+
+/// Settings key for the app-level list of packages that must never be auto-updated.
+pub const HOLD_LIST_KEY: &str = "packages.holdList";
 
 /// Resolves the path to the `install.json` file for the currently installed version of a package.
 /// This file contains metadata about the installation, including its hold status.
@@ -151,3 +154,93 @@ pub async fn unhold_package<R: Runtime>(
     let scoop_path = state.scoop_path();
     modify_hold_status(&scoop_path, &package_name, false)
 }
+
+/// Places a hold on a package using `scoop hold`, streaming the output to the frontend.
+#[tauri::command]
+pub async fn scoop_hold_package(
+    window: Window,
+    state: State<'_, AppState>,
+    package_name: String,
+) -> Result<(), String> {
+    crate::commands::uninstall::execute_package_operation(
+        window,
+        crate::commands::scoop::ScoopOp::Hold,
+        &package_name,
+        None,
+    )
+    .await?;
+    crate::commands::installed::invalidate_installed_cache(state).await;
+    Ok(())
+}
+
+/// Removes the hold on a package using `scoop unhold`, streaming the output to the frontend.
+#[tauri::command]
+pub async fn scoop_unhold_package(
+    window: Window,
+    state: State<'_, AppState>,
+    package_name: String,
+) -> Result<(), String> {
+    crate::commands::uninstall::execute_package_operation(
+        window,
+        crate::commands::scoop::ScoopOp::Unhold,
+        &package_name,
+        None,
+    )
+    .await?;
+    crate::commands::installed::invalidate_installed_cache(state).await;
+    Ok(())
+}
+
+/// Reads the app-level hold list from settings.
+pub fn read_hold_list<R: Runtime>(app: &AppHandle<R>) -> Vec<String> {
+    crate::commands::settings::get_config_value(app.clone(), HOLD_LIST_KEY.to_string())
+        .ok()
+        .flatten()
+        .and_then(|v| v.as_array().cloned())
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|v| v.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn write_hold_list(app: AppHandle<tauri::Wry>, list: Vec<String>) -> Result<(), String> {
+    crate::commands::settings::set_config_value(
+        app,
+        HOLD_LIST_KEY.to_string(),
+        serde_json::json!(list),
+    )
+}
+
+/// Returns the app-level hold list.
+#[tauri::command]
+pub fn get_hold_list(app: AppHandle<tauri::Wry>) -> Result<Vec<String>, String> {
+    Ok(read_hold_list(&app))
+}
+
+/// Adds a package to the app-level hold list so it is skipped by batch and automatic updates.
+#[tauri::command]
+pub fn add_to_hold_list(app: AppHandle<tauri::Wry>, package_name: String) -> Result<(), String> {
+    let mut list = read_hold_list(&app);
+    if list.iter().any(|n| n.eq_ignore_ascii_case(&package_name)) {
+        return Ok(());
+    }
+    log::info!("Adding '{}' to hold list", package_name);
+    list.push(package_name);
+    list.sort();
+    write_hold_list(app, list)
+}
+
+/// Removes a package from the app-level hold list.
+#[tauri::command]
+pub fn remove_from_hold_list(app: AppHandle<tauri::Wry>, package_name: String) -> Result<(), String> {
+    let mut list = read_hold_list(&app);
+    let before = list.len();
+    list.retain(|n| !n.eq_ignore_ascii_case(&package_name));
+    if list.len() == before {
+        return Ok(());
+    }
+    log::info!("Removing '{}' from hold list", package_name);
+    write_hold_list(app, list)
+}
@@ -4,7 +4,7 @@ use crate::utils;
 use serde::Serialize;
 use serde_json::Value;
 use std::fs;
-use tauri::State;
+use tauri::{AppHandle, Runtime, State};
 
 /// Represents the structured information for a Scoop package, suitable for frontend display.
 #[derive(Serialize, Debug, Clone, Default)]
@@ -181,7 +181,7 @@ pub fn get_package_info(
 }
 
 /// Gets the installed version of a package by reading its manifest file.
-fn get_installed_version(scoop_dir: &std::path::Path, package_name: &str) -> Option<String> {
+pub(crate) fn get_installed_version(scoop_dir: &std::path::Path, package_name: &str) -> Option<String> {
     let installed_manifest_path = scoop_dir
         .join("apps")
         .join(package_name)
@@ -218,3 +218,47 @@ fn get_installed_package_bucket(scoop_dir: &std::path::Path, package_name: &str)
     
     None
 }
+
+/// Opens the install directory (`apps/<name>/current`) of a package in the file manager.
+#[tauri::command]
+pub fn open_app_directory<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+    name: String,
+) -> Result<(), String> {
+    let mut components = std::path::Path::new(&name).components();
+    let is_plain_name = matches!(
+        (components.next(), components.next()),
+        (Some(std::path::Component::Normal(_)), None)
+    );
+    if !is_plain_name || name.contains(['/', '\\', ':']) {
+        return Err(format!("Invalid package name: '{}'", name));
+    }
+
+    let app_dir = crate::commands::scoop::apps_root(&app, &state)
+        .join("apps")
+        .join(&name)
+        .join("current");
+    if !app_dir.is_dir() {
+        return Err(format!("Install directory not found: {}", app_dir.display()));
+    }
+
+    log::info!("Opening install directory: {}", app_dir.display());
+    open_in_file_manager(&app_dir)
+}
+
+/// Opens a directory in the platform's file manager.
+pub(crate) fn open_in_file_manager(dir: &std::path::Path) -> Result<(), String> {
+    #[cfg(windows)]
+    let program = "explorer";
+    #[cfg(target_os = "macos")]
+    let program = "open";
+    #[cfg(all(unix, not(target_os = "macos")))]
+    let program = "xdg-open";
+
+    std::process::Command::new(program)
+        .arg(dir)
+        .spawn()
+        .map(|_| ())
+        .map_err(|e| format!("Failed to open {}: {}", dir.display(), e))
+}
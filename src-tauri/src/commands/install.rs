@@ -1,17 +1,44 @@
 //! Command for installing Scoop packages.
 use crate::commands::auto_cleanup::trigger_auto_cleanup;
 use crate::commands::installed::invalidate_installed_cache;
-use crate::commands::scoop::{self, ScoopOp};
+use crate::commands::manifest::validate_manifest;
+use crate::commands::scoop::{apps_root, validate_version, ScoopOp};
 use crate::commands::search::invalidate_manifest_cache;
+use crate::commands::uninstall::{
+    execute_package_operation, execute_package_operation_checked, execute_package_operation_with_id,
+};
+use crate::error::AppError;
 use crate::state::AppState;
+use serde_json::Value;
+use std::path::Path;
 use tauri::{AppHandle, State, Window};
 
-/// Installs a Scoop package, optionally from a specific bucket.
+/// Settings key enabling the post-install verification step.
+pub const VERIFY_AFTER_INSTALL_KEY: &str = "install.verifyAfter";
+
+/// Outcome of the post-install verification.
+#[derive(Debug, Clone)]
+pub struct InstallVerification {
+    pub success: bool,
+    /// Whether `apps/<name>/current` exists.
+    pub current_exists: bool,
+    /// Shims declared by the manifest's `bin` field that were not found.
+    pub missing_shims: Vec<String>,
+}
+
+/// Installs a Scoop package, optionally from a specific bucket and at a specific version.
 ///
 /// # Arguments
 /// * `window` - The Tauri window to emit events to.
 /// * `package_name` - The name of package to install.
 /// * `bucket` - The name of bucket to install from. If empty or "None", default buckets are used.
+/// * `version` - An optional version to install (`scoop install <name>@<version>`). Scoop
+///   installs such packages as versioned installs, which are then excluded from cleanup.
+/// * `operation_id` - The id the output is streamed under; generated when omitted.
+///
+/// When `install.verifyAfter` is enabled, the installed app directory and its shims are
+/// checked before `operation-finished` is emitted, and an `OPERATION_FAILED` error is
+/// returned if they are missing.
 #[tauri::command]
 pub async fn install_package(
     window: Window,
@@ -19,24 +46,266 @@ pub async fn install_package(
     state: State<'_, AppState>,
     package_name: String,
     bucket: String,
-) -> Result<(), String> {
+    version: Option<String>,
+    operation_id: Option<String>,
+) -> Result<(), AppError> {
     let bucket_opt =
         (!bucket.is_empty() && !bucket.eq_ignore_ascii_case("none")).then(|| bucket.as_str());
 
+    let target = match version.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
+        Some(version) => {
+            validate_version(version).map_err(AppError::InvalidInput)?;
+            format!("{}@{}", package_name, version)
+        }
+        None => package_name.clone(),
+    };
+
     log::info!(
         "Installing package '{}' from bucket '{}'",
-        package_name,
+        target,
         bucket_opt.unwrap_or("default")
     );
 
-    let operation_id = Some(format!("install-{}-{}", package_name, std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs()));
+    let verify = crate::commands::settings::get_config_value(
+        app.clone(),
+        VERIFY_AFTER_INSTALL_KEY.to_string(),
+    )
+    .ok()
+    .flatten()
+    .and_then(|v| v.as_bool())
+    .unwrap_or(false);
 
-    scoop::execute_scoop(window, ScoopOp::Install, Some(&package_name), bucket_opt, operation_id).await?;
+    let result = if verify {
+        let scoop_path = apps_root(&app, &state);
+        let name = package_name.clone();
+        let check = Box::new(move || verification_outcome(&scoop_path, &name));
+        execute_package_operation_checked(
+            window,
+            ScoopOp::Install,
+            &target,
+            bucket_opt,
+            operation_id,
+            check,
+        )
+        .await
+    } else {
+        execute_package_operation_with_id(
+            window,
+            ScoopOp::Install,
+            &target,
+            bucket_opt,
+            operation_id,
+        )
+        .await
+    };
     invalidate_manifest_cache().await;
     invalidate_installed_cache(state.clone()).await;
+    result.map_err(AppError::OperationFailed)?;
 
     // Trigger auto cleanup after install
     trigger_auto_cleanup(app, state).await;
 
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Installs a package from a local manifest file with `scoop install <path>`.
+///
+/// The manifest is checked with `validate_manifest` first and the install is refused
+/// if it has any issues.
+#[tauri::command]
+pub async fn install_from_manifest(
+    window: Window,
+    app: AppHandle,
+    state: State<'_, AppState>,
+    path: String,
+) -> Result<(), AppError> {
+    let summary = validate_manifest(path.clone()).map_err(AppError::InvalidInput)?;
+    if !summary.valid {
+        let issues: Vec<String> = summary
+            .issues
+            .iter()
+            .map(|issue| match issue.line {
+                Some(line) => format!("line {}: {}", line, issue.message),
+                None => issue.message.clone(),
+            })
+            .collect();
+        return Err(AppError::InvalidInput(format!(
+            "Manifest {} is invalid: {}",
+            path,
+            issues.join("; ")
+        )));
+    }
+
+    log::info!("Installing '{}' from local manifest {}", summary.name, path);
+    // Quote the path for PowerShell; embedded single quotes are escaped by doubling
+    let target = format!("'{}'", path.replace('\'', "''"));
+    execute_package_operation(window, ScoopOp::Install, &target, None)
+        .await
+        .map_err(AppError::OperationFailed)?;
+    invalidate_manifest_cache().await;
+    invalidate_installed_cache(state.clone()).await;
+
+    trigger_auto_cleanup(app, state).await;
+    Ok(())
+}
+
+/// Reinstalls a package by uninstalling and installing it again.
+///
+/// Scoop only deletes `persist/<name>` when uninstalling with `--purge`, which is never
+/// passed here, so persisted user configuration and data survive the reinstall. This is
+/// verified after the uninstall step and the reinstall is aborted if the data is gone.
+///
+/// # Arguments
+/// * `bucket` - The bucket to install from. Defaults to the bucket recorded in the
+///   installed app's `install.json`.
+#[tauri::command]
+pub async fn reinstall_package(
+    window: Window,
+    app: AppHandle,
+    state: State<'_, AppState>,
+    package_name: String,
+    bucket: Option<String>,
+) -> Result<(), AppError> {
+    let scoop_path = apps_root(&app, &state);
+    let bucket = bucket
+        .filter(|b| !b.is_empty() && !b.eq_ignore_ascii_case("none"))
+        .or_else(|| installed_bucket(&scoop_path, &package_name));
+    let persist_dir = scoop_path.join("persist").join(&package_name);
+    let had_persist = persist_dir.is_dir();
+
+    log::info!(
+        "Reinstalling package '{}' from bucket '{}' (persisted data: {})",
+        package_name,
+        bucket.as_deref().unwrap_or("default"),
+        had_persist
+    );
+
+    let result = async {
+        execute_package_operation(window.clone(), ScoopOp::Uninstall, &package_name, None)
+            .await
+            .map_err(AppError::OperationFailed)?;
+
+        if had_persist && !persist_dir.is_dir() {
+            return Err(AppError::OperationFailed(format!(
+                "Persisted data for '{}' was removed during uninstall; not reinstalling",
+                package_name
+            )));
+        }
+
+        execute_package_operation(window, ScoopOp::Install, &package_name, bucket.as_deref())
+            .await
+            .map_err(AppError::OperationFailed)?;
+        Ok::<(), AppError>(())
+    }
+    .await;
+
+    // Both steps change the installed set, so invalidate once regardless of the outcome
+    invalidate_manifest_cache().await;
+    invalidate_installed_cache(state.clone()).await;
+
+    if result.is_ok() {
+        trigger_auto_cleanup(app, state).await;
+    }
+    result
+}
+
+/// Reads the bucket an installed package came from, as recorded in its `install.json`.
+fn installed_bucket(scoop_path: &Path, package_name: &str) -> Option<String> {
+    let path = scoop_path
+        .join("apps")
+        .join(package_name)
+        .join("current")
+        .join("install.json");
+    let content = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str::<Value>(&content)
+        .ok()?
+        .get("bucket")
+        .and_then(Value::as_str)
+        .map(|b| b.to_string())
+}
+
+/// Runs `verify_installation` and turns it into the install outcome message.
+fn verification_outcome(scoop_path: &Path, package_name: &str) -> Result<String, String> {
+    let verification = verify_installation(scoop_path, package_name);
+    if verification.success {
+        log::info!("Verified installation of '{}'", package_name);
+        return Ok(format!("Installed and verified {}", package_name));
+    }
+
+    let message = if verification.current_exists {
+        format!(
+            "'{}' was installed but its shims are missing: {}",
+            package_name,
+            verification.missing_shims.join(", ")
+        )
+    } else {
+        format!(
+            "'{}' did not install correctly: apps/{}/current is missing",
+            package_name, package_name
+        )
+    };
+    log::error!("{}", message);
+    Err(message)
+}
+
+/// Checks that `apps/<name>/current` exists and that every shim declared by the
+/// installed manifest's `bin` field is present in the shims directory.
+fn verify_installation(scoop_path: &Path, package_name: &str) -> InstallVerification {
+    let current_dir = scoop_path.join("apps").join(package_name).join("current");
+    let current_exists = current_dir.is_dir();
+
+    let shims_dir = scoop_path.join("shims");
+    let missing_shims: Vec<String> = if current_exists {
+        std::fs::read_to_string(current_dir.join("manifest.json"))
+            .ok()
+            .and_then(|content| serde_json::from_str::<Value>(&content).ok())
+            .map(|manifest| bin_shim_names(&manifest))
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|shim| {
+                !["exe", "shim", "cmd", "ps1"]
+                    .iter()
+                    .any(|ext| shims_dir.join(format!("{}.{}", shim, ext)).exists())
+                    && !shims_dir.join(shim).exists()
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    InstallVerification {
+        success: current_exists && missing_shims.is_empty(),
+        current_exists,
+        missing_shims,
+    }
+}
+
+/// Returns the shim names declared by a manifest's `bin` field.
+///
+/// `bin` may be a single path, or an array whose items are either a path or
+/// `[path, alias, args...]`; the shim is named after the alias or the file stem.
+fn bin_shim_names(manifest: &Value) -> Vec<String> {
+    let stem = |path: &str| {
+        Path::new(&path.replace('\\', "/"))
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+    };
+
+    let entries: Vec<&Value> = match manifest.get("bin") {
+        Some(Value::Array(items)) => items.iter().collect(),
+        Some(value @ Value::String(_)) => vec![value],
+        _ => Vec::new(),
+    };
+
+    entries
+        .into_iter()
+        .filter_map(|entry| match entry {
+            Value::String(path) => stem(path),
+            Value::Array(parts) => match parts.get(1).and_then(Value::as_str) {
+                Some(alias) if !alias.is_empty() => Some(alias.to_string()),
+                _ => parts.first().and_then(Value::as_str).and_then(stem),
+            },
+            _ => None,
+        })
+        .collect()
+}
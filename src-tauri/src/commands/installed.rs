@@ -6,7 +6,17 @@ use rayon::prelude::*;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::UNIX_EPOCH;
-use tauri::{AppHandle, Runtime, State};
+use tauri::{AppHandle, Emitter, Runtime, State};
+
+/// Event emitted when a rescan produces a different installed packages fingerprint.
+pub const EVENT_INSTALLED_CACHE_UPDATED: &str = "installed-cache-updated";
+
+/// Payload for the `installed-cache-updated` event.
+#[derive(serde::Serialize, Clone)]
+pub struct InstalledCacheUpdated {
+    pub fingerprint: String,
+    pub package_count: usize,
+}
 
 /// Helper to get modification time of a path (file or directory) in milliseconds.
 fn get_path_modification_time(path: &Path) -> u128 {
@@ -401,6 +411,17 @@ async fn scan_installed_packages_internal<R: Runtime>(
     // Update cache
     update_cache(state, packages.clone(), fingerprint.clone(), log_prefix).await;
 
+    if state.record_installed_fingerprint(&fingerprint) {
+        log::info!("{} Installed packages changed, notifying frontend", log_prefix);
+        let _ = app.emit(
+            EVENT_INSTALLED_CACHE_UPDATED,
+            InstalledCacheUpdated {
+                fingerprint: fingerprint.clone(),
+                package_count: packages.len(),
+            },
+        );
+    }
+
     log::debug!(
         "{} ✓ Returning {} installed packages",
         log_prefix,
@@ -416,8 +437,12 @@ pub async fn get_installed_packages_full<R: Runtime>(
 ) -> Result<Vec<ScoopPackage>, String> {
     log::info!("=== INSTALLED SCAN === get_installed_packages_full called");
 
+    let hold_list = crate::commands::hold::read_hold_list(&app);
+
     // Perform the scan (cache is checked inside)
-    let result = scan_installed_packages_internal(app, &state, false).await;
+    let result = scan_installed_packages_internal(app, &state, false)
+        .await
+        .map(|packages| mark_held_packages(packages, &hold_list));
     log::info!(
         "=== INSTALLED SCAN === get_installed_packages_full completed, result: {:?}",
         result.as_ref().map(|pkgs| pkgs.len())
@@ -425,6 +450,171 @@ pub async fn get_installed_packages_full<R: Runtime>(
     result
 }
 
+/// Flags packages that are on the app-level hold list.
+fn mark_held_packages(mut packages: Vec<ScoopPackage>, hold_list: &[String]) -> Vec<ScoopPackage> {
+    for package in &mut packages {
+        package.is_held = hold_list
+            .iter()
+            .any(|held| held.eq_ignore_ascii_case(&package.name));
+    }
+    packages
+}
+
+/// Cached and freshly computed installed packages fingerprints.
+#[derive(serde::Serialize, Clone, Debug)]
+pub struct FingerprintComparison {
+    /// Fingerprint stored with the installed packages cache, if the cache is populated.
+    pub cached: Option<String>,
+    /// Fingerprint computed from the apps directory right now.
+    pub fresh: String,
+    #[serde(rename = "match")]
+    pub matches: bool,
+}
+
+/// Recomputes the apps fingerprint from disk and compares it with the cached one.
+///
+/// The cache itself is left untouched; this only reports whether it is stale.
+#[tauri::command]
+pub async fn recompute_fingerprint<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+) -> Result<FingerprintComparison, String> {
+    let apps_path = crate::commands::scoop::apps_root(&app, &state).join("apps");
+
+    let app_dirs: Vec<PathBuf> = if apps_path.is_dir() {
+        list_app_dirs(&apps_path)?
+    } else {
+        Vec::new()
+    };
+
+    let fresh = compute_apps_fingerprint(&app_dirs);
+    let cached = state
+        .installed_packages
+        .lock()
+        .await
+        .as_ref()
+        .map(|cache| cache.fingerprint.clone());
+    let matches = cached.as_deref() == Some(fresh.as_str());
+
+    log::info!(
+        "Fingerprint check: cached={:?}, fresh={}, match={}",
+        cached,
+        fresh,
+        matches
+    );
+
+    Ok(FingerprintComparison {
+        cached,
+        fresh,
+        matches,
+    })
+}
+
+/// Lists the app directories below `apps_path`.
+fn list_app_dirs(apps_path: &Path) -> Result<Vec<PathBuf>, String> {
+    Ok(fs::read_dir(apps_path)
+        .map_err(|e| format!("Failed to read apps directory: {}", e))?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .collect())
+}
+
+/// Entries of an apps fingerprint other than `name`'s, used to tell whether anything
+/// besides that app changed between two fingerprints.
+fn fingerprint_entries_except<'a>(fingerprint: &'a str, name: &str) -> Vec<&'a str> {
+    let prefix = format!("{}:", name.to_ascii_lowercase());
+    fingerprint
+        .split_once('|')
+        .map_or("", |(_, entries)| entries)
+        .split(';')
+        .filter(|entry| !entry.is_empty() && !entry.starts_with(&prefix))
+        .collect()
+}
+
+/// Re-reads a single package from disk and patches its entry in the installed cache.
+///
+/// The fresh fingerprint is only adopted if no other app changed since the cache was
+/// built; otherwise, as for packages that are not in the cache, the whole cache is
+/// invalidated so those changes are picked up by the next scan. Returns the refreshed
+/// package, or `None` if it is no longer installed or the cache was invalidated.
+#[tauri::command]
+pub async fn refresh_package<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+    name: String,
+) -> Result<Option<ScoopPackage>, String> {
+    let scoop_path = state.scoop_path();
+    let apps_path = crate::commands::scoop::apps_root(&app, &state).join("apps");
+
+    let mut cache_guard = state.installed_packages.lock().await;
+    let Some(cache) = cache_guard.as_mut() else {
+        drop(cache_guard);
+        log::info!("No installed cache to patch for '{}', invalidating", name);
+        invalidate_installed_cache(state).await;
+        return Ok(None);
+    };
+    let Some(index) = cache
+        .packages
+        .iter()
+        .position(|p| p.name.eq_ignore_ascii_case(&name))
+    else {
+        drop(cache_guard);
+        log::info!("'{}' is not in the installed cache, invalidating", name);
+        invalidate_installed_cache(state).await;
+        return Ok(None);
+    };
+
+    let fingerprint = compute_apps_fingerprint(&list_app_dirs(&apps_path)?);
+    if fingerprint_entries_except(&cache.fingerprint, &name)
+        != fingerprint_entries_except(&fingerprint, &name)
+    {
+        drop(cache_guard);
+        log::info!("Other apps changed since the installed cache was built, invalidating");
+        invalidate_installed_cache(state).await;
+        return Ok(None);
+    }
+
+    let refreshed = match load_package_details(&apps_path.join(&name), &scoop_path) {
+        Ok(package) => {
+            cache.packages[index] = package.clone();
+            Some(package)
+        }
+        Err(e) => {
+            log::info!("Removing '{}' from the installed cache: {}", name, e);
+            cache.packages.remove(index);
+            None
+        }
+    };
+
+    cache.fingerprint = fingerprint.clone();
+    let package_count = cache.packages.len();
+    drop(cache_guard);
+
+    // Both derived caches are keyed by the old fingerprint
+    *state.package_versions.lock().await = None;
+    *state.disk_usage.lock().await = None;
+
+    if state.record_installed_fingerprint(&fingerprint) {
+        let _ = app.emit(
+            EVENT_INSTALLED_CACHE_UPDATED,
+            InstalledCacheUpdated {
+                fingerprint,
+                package_count,
+            },
+        );
+    }
+
+    log::info!("Refreshed installed metadata for '{}'", name);
+    let hold_list = crate::commands::hold::read_hold_list(&app);
+    Ok(refreshed.map(|mut package| {
+        package.is_held = hold_list
+            .iter()
+            .any(|held| held.eq_ignore_ascii_case(&package.name));
+        package
+    }))
+}
+
 /// Invalidates the cached list of installed packages in AppState.
 /// This should be called after operations that change the installed packages,
 /// such as installing or uninstalling a package.
@@ -436,9 +626,10 @@ pub async fn invalidate_installed_cache(state: State<'_, AppState>) {
     // Also invalidate the versions cache since it depends on installed packages
     let mut versions_guard = state.package_versions.lock().await;
     *versions_guard = None;
+    *state.disk_usage.lock().await = None;
 
     log::info!(
-        "=== INSTALLED CACHE === Cache invalidated (was_cached: {}). Also invalidated versions cache.",
+        "=== INSTALLED CACHE === Cache invalidated (was_cached: {}). Also invalidated versions and disk usage caches.",
         was_cached
     );
 }
@@ -473,7 +664,10 @@ pub async fn refresh_installed_packages<R: Runtime>(
 
     // Then fetch fresh data
     log::info!("=== INSTALLED REFRESH === Fetching fresh data");
-    let result = scan_installed_packages_internal(app, &state, false).await;
+    let hold_list = crate::commands::hold::read_hold_list(&app);
+    let result = scan_installed_packages_internal(app, &state, false)
+        .await
+        .map(|packages| mark_held_packages(packages, &hold_list));
     log::info!("=== INSTALLED REFRESH === refresh_installed_packages completed");
     result
 }
@@ -481,11 +675,13 @@ pub async fn refresh_installed_packages<R: Runtime>(
 /// Gets the installation path for a specific package.
 #[tauri::command]
 pub async fn get_package_path<R: Runtime>(
-    _app: AppHandle<R>,
+    app: AppHandle<R>,
     state: State<'_, AppState>,
     package_name: String,
 ) -> Result<String, String> {
-    let package_path = state.scoop_path().join("apps").join(&package_name);
+    let package_path = crate::commands::scoop::apps_root(&app, &state)
+        .join("apps")
+        .join(&package_name);
 
     if !package_path.exists() {
         return Err(format!("Package '{}' is not installed", package_name));
@@ -499,6 +695,12 @@ async fn ensure_apps_path<R: Runtime>(
     state: &AppState,
     log_prefix: &str,
 ) -> Option<PathBuf> {
+    // Global apps live outside the detected per-user root, which is not refreshed for them
+    if crate::commands::scoop::global_enabled(&app) {
+        let apps_path = crate::commands::scoop::global_root().join("apps");
+        return apps_path.is_dir().then_some(apps_path);
+    }
+
     let mut scoop_path = state.scoop_path();
     let mut apps_path = scoop_path.join("apps");
 
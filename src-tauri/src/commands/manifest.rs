@@ -1,8 +1,11 @@
 //! Command for fetching the raw JSON manifest of a Scoop package.
 use crate::state::AppState;
 use crate::utils;
+use serde::Serialize;
+use serde_json::Value;
 use std::fs;
-use tauri::State;
+use std::path::Path;
+use tauri::{AppHandle, Runtime, State};
 
 /// Fetches the manifest content for a given package from a specific bucket.
 ///
@@ -35,3 +38,516 @@ pub fn get_package_manifest(
     fs::read_to_string(&manifest_path)
         .map_err(|e| format!("Failed to read manifest for {}: {}", package_name, e))
 }
+
+/// Manifest fields that are always present in the result of `get_manifest`, as `null`
+/// when the manifest does not define them.
+const DETAIL_FIELDS: &[&str] = &["homepage", "description", "license", "version", "notes"];
+
+/// Returns the parsed manifest of a package for the app details panel.
+///
+/// Installed packages use their `apps/<name>/current/manifest.json`, so the details match
+/// the installed version; otherwise the manifest is looked up in `bucket` (or all buckets).
+#[tauri::command]
+pub fn get_manifest<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+    name: String,
+    bucket: Option<String>,
+) -> Result<Value, String> {
+    let scoop_dir = state.scoop_path();
+
+    let installed_manifest = crate::commands::scoop::apps_root(&app, &state)
+        .join("apps")
+        .join(&name)
+        .join("current")
+        .join("manifest.json");
+
+    let manifest_path = if installed_manifest.is_file() {
+        installed_manifest
+    } else {
+        let bucket = bucket.filter(|b| !b.is_empty() && !b.eq_ignore_ascii_case("none"));
+        utils::locate_package_manifest(&scoop_dir, &name, bucket)?.0
+    };
+
+    log::info!("Reading manifest for '{}' from {}", name, manifest_path.display());
+
+    let content = fs::read_to_string(&manifest_path)
+        .map_err(|e| format!("Failed to read manifest for {}: {}", name, e))?;
+    let mut manifest: Value = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse manifest for {}: {}", name, e))?;
+
+    let obj = manifest
+        .as_object_mut()
+        .ok_or_else(|| format!("Manifest for {} is not a JSON object", name))?;
+    for field in DETAIL_FIELDS {
+        obj.entry(field.to_string()).or_insert(Value::Null);
+    }
+
+    Ok(manifest)
+}
+
+/// Lists the versions of a package that can be installed or rolled back to, newest first.
+///
+/// Only the latest manifest version is available from a bucket, so this returns the
+/// version from the manifest in `bucket` (or any bucket) plus every version already
+/// present under `apps/<name>/`.
+#[tauri::command]
+pub fn list_available_versions<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+    name: String,
+    bucket: String,
+) -> Result<Vec<String>, String> {
+    let scoop_dir = state.scoop_path();
+    let bucket_option =
+        (!bucket.is_empty() && !bucket.eq_ignore_ascii_case("none")).then(|| bucket);
+
+    let mut versions = Vec::new();
+    match utils::locate_package_manifest(&scoop_dir, &name, bucket_option) {
+        Ok((manifest_path, _)) => {
+            let version = fs::read_to_string(&manifest_path)
+                .ok()
+                .and_then(|content| serde_json::from_str::<Value>(&content).ok())
+                .and_then(|manifest| manifest.get("version")?.as_str().map(str::to_string));
+            versions.extend(version);
+        }
+        Err(e) => log::debug!("No bucket manifest for '{}': {}", name, e),
+    }
+
+    let apps_dir = crate::commands::scoop::apps_root(&app, &state).join("apps");
+    if let Ok(entries) = fs::read_dir(apps_dir.join(&name)) {
+        versions.extend(
+            entries
+                .flatten()
+                .filter(|entry| entry.path().is_dir())
+                .map(|entry| entry.file_name().to_string_lossy().to_string())
+                .filter(|version| version != "current"),
+        );
+    }
+
+    if versions.is_empty() {
+        return Err(format!("No versions found for package '{}'", name));
+    }
+
+    versions.sort_by(|a, b| crate::commands::auto_cleanup::compare_versions(b, a).then(b.cmp(a)));
+    versions.dedup();
+    log::info!("Available versions for '{}': {}", name, versions.join(", "));
+    Ok(versions)
+}
+
+/// Size estimate shown before installing a package. Fields are `None` when the size
+/// cannot be determined rather than guessed.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct InstallSizeEstimate {
+    /// Total size of the files downloaded by the install, known only when they are
+    /// already in Scoop's download cache.
+    pub download_bytes: Option<u64>,
+    /// Size of the currently installed version, if the package is already installed.
+    pub current_installed_bytes: Option<u64>,
+}
+
+/// Maps the spellings accepted by Scoop's `default_architecture` to manifest keys.
+fn normalize_architecture(arch: &str) -> Option<&'static str> {
+    match arch.to_ascii_lowercase().as_str() {
+        "64bit" | "x64" | "amd64" | "x86_64" => Some("64bit"),
+        "32bit" | "x86" | "i386" | "386" => Some("32bit"),
+        "arm64" | "aarch64" => Some("arm64"),
+        _ => None,
+    }
+}
+
+/// The architecture Scoop installs for: its `default_architecture` setting, or else the
+/// one of the running Windows, detected through the same environment variables Scoop uses.
+fn scoop_architecture() -> &'static str {
+    let configured = crate::commands::settings::get_scoop_config()
+        .ok()
+        .flatten()
+        .and_then(|config| {
+            config
+                .get("default_architecture")
+                .and_then(Value::as_str)
+                .and_then(normalize_architecture)
+        });
+    configured.unwrap_or_else(|| {
+        if std::env::var_os("ProgramFiles(Arm)").is_some() {
+            "arm64"
+        } else if std::env::var_os("ProgramFiles(x86)").is_some() {
+            "64bit"
+        } else {
+            "32bit"
+        }
+    })
+}
+
+/// Returns the download URLs Scoop would use for `arch`.
+///
+/// As in Scoop, a manifest without an `arm64` entry is installed as `64bit` on ARM
+/// (through x64 emulation), and the top-level `url` applies when the architecture has
+/// none of its own. Scoop's `#/name.ext` rename suffix is stripped from the URLs.
+fn manifest_urls(manifest: &Value, arch: &str) -> Vec<String> {
+    let architectures = manifest.get("architecture");
+    let arch = match arch {
+        "arm64" if architectures.and_then(|a| a.get("arm64")).is_none() => "64bit",
+        arch => arch,
+    };
+    let urls = architectures
+        .and_then(|a| a.get(arch)?.get("url"))
+        .or_else(|| manifest.get("url"));
+
+    let urls: Vec<&str> = match urls {
+        Some(Value::String(url)) => vec![url.as_str()],
+        Some(Value::Array(items)) => items.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    };
+    urls.into_iter()
+        .map(|url| url.split('#').next().unwrap_or(url).to_string())
+        .collect()
+}
+
+/// Sums the cached downloads of `name` at `version`, if there is one per URL.
+///
+/// Scoop names cache files `name#version#<url hash>`.
+fn cached_download_bytes(
+    cache_dir: &Path,
+    name: &str,
+    version: &str,
+    url_count: usize,
+) -> Option<u64> {
+    let prefix = format!("{}#{}#", name, version).to_lowercase();
+    let sizes: Vec<u64> = fs::read_dir(cache_dir)
+        .ok()?
+        .filter_map(Result::ok)
+        .filter(|e| {
+            e.file_name()
+                .to_string_lossy()
+                .to_lowercase()
+                .starts_with(&prefix)
+        })
+        .filter_map(|e| e.metadata().ok().filter(|m| m.is_file()).map(|m| m.len()))
+        .collect();
+    (url_count > 0 && sizes.len() == url_count).then(|| sizes.iter().sum())
+}
+
+/// Estimates how much space installing a package needs.
+///
+/// Manifests carry no size information and this query makes no network requests, so the
+/// download size is only reported when Scoop has already cached the downloads for the
+/// manifest's version and the host architecture.
+#[tauri::command]
+pub fn estimate_install_size<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+    name: String,
+    bucket: String,
+) -> Result<InstallSizeEstimate, String> {
+    let scoop_dir = state.scoop_path();
+    let bucket_option =
+        (!bucket.is_empty() && !bucket.eq_ignore_ascii_case("none")).then(|| bucket);
+
+    let (manifest_path, _) = utils::locate_package_manifest(&scoop_dir, &name, bucket_option)?;
+    let content = fs::read_to_string(&manifest_path)
+        .map_err(|e| format!("Failed to read manifest for {}: {}", name, e))?;
+    let manifest: Value = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse manifest for {}: {}", name, e))?;
+
+    let urls = manifest_urls(&manifest, scoop_architecture());
+    let download_bytes = manifest
+        .get("version")
+        .and_then(Value::as_str)
+        .and_then(|version| {
+            cached_download_bytes(&scoop_dir.join("cache"), &name, version, urls.len())
+        });
+
+    let current_dir = crate::commands::scoop::apps_root(&app, &state)
+        .join("apps")
+        .join(&name)
+        .join("current");
+    let current_installed_bytes = fs::canonicalize(&current_dir)
+        .ok()
+        .map(|dir| crate::commands::doctor::disk_usage::dir_size(&dir));
+
+    log::info!(
+        "Install size estimate for '{}': download {:?}, installed {:?}",
+        name,
+        download_bytes,
+        current_installed_bytes
+    );
+    Ok(InstallSizeEstimate {
+        download_bytes,
+        current_installed_bytes,
+    })
+}
+
+/// A problem found by `validate_manifest`.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestIssue {
+    pub field: String,
+    pub message: String,
+    /// 1-based line of the field in the file, when it is present.
+    pub line: Option<usize>,
+}
+
+/// Result of validating a local manifest file.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ManifestSummary {
+    /// Package name Scoop derives from the file name.
+    pub name: String,
+    pub version: Option<String>,
+    pub description: Option<String>,
+    /// Architectures declared under `architecture`.
+    pub architectures: Vec<String>,
+    pub valid: bool,
+    pub issues: Vec<ManifestIssue>,
+}
+
+/// Returns the 1-based line of the member at `path`, e.g. `["architecture", "64bit", "url"]`.
+///
+/// Each key is only looked up inside the object of the previous one, so a field is
+/// never attributed to another section that happens to declare the same key.
+fn line_of(content: &str, path: &[&str]) -> Option<usize> {
+    let mut object = 0..content.len();
+    let mut key_offset = None;
+    for key in path {
+        let (offset, value) = find_member(&content[object.clone()], key)?;
+        key_offset = Some(object.start + offset);
+        object = object.start + value.start..object.start + value.end;
+    }
+    Some(content[..key_offset?].matches('\n').count() + 1)
+}
+
+/// Finds `key` among the direct members of the JSON object in `object`, returning the
+/// offset of the key and the byte range of its value.
+fn find_member(object: &str, key: &str) -> Option<(usize, std::ops::Range<usize>)> {
+    let bytes = object.as_bytes();
+    let quoted = format!("\"{}\"", key);
+    let mut depth = 0usize;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => {
+                let end = string_end(bytes, i);
+                if depth == 1 && object[i..end] == quoted {
+                    if let Some(after_colon) = object[end..].trim_start().strip_prefix(':') {
+                        let value_start = object.len() - after_colon.trim_start().len();
+                        return Some((i, value_start..value_end(bytes, value_start)));
+                    }
+                }
+                i = end;
+                continue;
+            }
+            b'{' | b'[' => depth += 1,
+            b'}' | b']' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Returns the offset just past the string literal starting at `start`.
+fn string_end(bytes: &[u8], start: usize) -> usize {
+    let mut i = start + 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'"' => return i + 1,
+            _ => i += 1,
+        }
+    }
+    bytes.len()
+}
+
+/// Returns the offset just past the JSON value starting at `start`.
+fn value_end(bytes: &[u8], start: usize) -> usize {
+    let mut depth = 0usize;
+    let mut i = start;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'"' => {
+                i = string_end(bytes, i);
+                continue;
+            }
+            b'{' | b'[' => depth += 1,
+            b'}' | b']' if depth == 0 => return i,
+            b'}' | b']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return i + 1;
+                }
+            }
+            b',' if depth == 0 => return i,
+            _ => {}
+        }
+        i += 1;
+    }
+    bytes.len()
+}
+
+/// Counts the entries of a field that may be a single string or an array of strings.
+fn string_or_array_len(value: Option<&Value>) -> Option<usize> {
+    match value? {
+        Value::String(_) => Some(1),
+        Value::Array(items) if items.iter().all(Value::is_string) => Some(items.len()),
+        _ => None,
+    }
+}
+
+/// Checks the `url`/`hash` pair of a manifest or one of its architecture entries.
+///
+/// `section_path` is empty for the top level and e.g. `["architecture", "64bit"]` otherwise.
+fn check_download(
+    section: &Value,
+    section_path: &[&str],
+    nightly: bool,
+    content: &str,
+    issues: &mut Vec<ManifestIssue>,
+) {
+    let mut issue = |field: &str, message: String| {
+        // A missing field is reported on the line of its section
+        let line = line_of(content, &[section_path, &[field]].concat())
+            .or_else(|| line_of(content, section_path));
+        let prefix: String = section_path.iter().map(|key| format!("{}.", key)).collect();
+        issues.push(ManifestIssue {
+            field: format!("{}{}", prefix, field),
+            message,
+            line,
+        });
+    };
+
+    let url_count = match string_or_array_len(section.get("url")) {
+        Some(count) => count,
+        None => {
+            if section.get("url").is_some() {
+                issue("url", "Must be a string or an array of strings".to_string());
+            }
+            return;
+        }
+    };
+
+    // Nightly manifests download a moving target and are installed without a hash
+    if nightly {
+        return;
+    }
+    match (
+        section.get("hash"),
+        string_or_array_len(section.get("hash")),
+    ) {
+        (None, _) => issue("hash", "Missing hash for the download URL".to_string()),
+        (Some(_), None) => issue(
+            "hash",
+            "Must be a string or an array of strings".to_string(),
+        ),
+        (Some(_), Some(hash_count)) if hash_count != url_count => issue(
+            "hash",
+            format!("{} URLs but {} hashes", url_count, hash_count),
+        ),
+        _ => {}
+    }
+}
+
+/// Parses a local manifest file and checks the fields Scoop needs to install it.
+///
+/// A manifest needs a `version` and either a `url` with a matching `hash` or an
+/// `installer`, at the top level or for every declared architecture.
+#[tauri::command]
+pub fn validate_manifest(path: String) -> Result<ManifestSummary, String> {
+    let file = std::path::Path::new(&path);
+    let name = file
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .ok_or_else(|| format!("Not a manifest file: {}", path))?;
+    let content =
+        fs::read_to_string(file).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+
+    let manifest: Value = match serde_json::from_str(&content) {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            return Ok(ManifestSummary {
+                name,
+                version: None,
+                description: None,
+                architectures: Vec::new(),
+                valid: false,
+                issues: vec![ManifestIssue {
+                    field: String::new(),
+                    message: format!("Invalid JSON: {}", e),
+                    line: Some(e.line()),
+                }],
+            })
+        }
+    };
+
+    let mut issues = Vec::new();
+    if !manifest.is_object() {
+        issues.push(ManifestIssue {
+            field: String::new(),
+            message: "The manifest must be a JSON object".to_string(),
+            line: Some(1),
+        });
+    }
+
+    let version = manifest
+        .get("version")
+        .and_then(Value::as_str)
+        .map(str::to_string);
+    match (&version, manifest.get("version")) {
+        (None, None) => issues.push(ManifestIssue {
+            field: "version".to_string(),
+            message: "Missing version".to_string(),
+            line: None,
+        }),
+        (None, Some(_)) => issues.push(ManifestIssue {
+            field: "version".to_string(),
+            message: "Must be a string".to_string(),
+            line: line_of(&content, &["version"]),
+        }),
+        _ => {}
+    }
+    let nightly = version.as_deref() == Some("nightly");
+
+    let architectures: Vec<(String, Value)> = manifest
+        .get("architecture")
+        .and_then(Value::as_object)
+        .map(|map| map.iter().map(|(k, v)| (k.clone(), v.clone())).collect())
+        .unwrap_or_default();
+
+    let has_download =
+        |section: &Value| section.get("url").is_some() || section.get("installer").is_some();
+    if has_download(&manifest) {
+        check_download(&manifest, &[], nightly, &content, &mut issues);
+    } else if architectures.is_empty() {
+        issues.push(ManifestIssue {
+            field: "url".to_string(),
+            message: "Missing url or installer".to_string(),
+            line: None,
+        });
+    }
+    for (arch, section) in &architectures {
+        let section_path = ["architecture", arch.as_str()];
+        if has_download(section) {
+            check_download(section, &section_path, nightly, &content, &mut issues);
+        } else if !has_download(&manifest) {
+            issues.push(ManifestIssue {
+                field: format!("architecture.{}.url", arch),
+                message: format!("Missing url or installer for {}", arch),
+                line: line_of(&content, &section_path),
+            });
+        }
+    }
+
+    log::info!("Validated manifest {}: {} issue(s)", path, issues.len());
+    Ok(ManifestSummary {
+        name,
+        version,
+        description: manifest
+            .get("description")
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        architectures: architectures.into_iter().map(|(arch, _)| arch).collect(),
+        valid: issues.is_empty(),
+        issues,
+    })
+}
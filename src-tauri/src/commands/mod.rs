@@ -1,4 +1,5 @@
 pub mod app_info;
+pub mod apps_watcher;
 pub mod auto_cleanup;
 pub mod bucket;
 pub mod bucket_install;
@@ -6,6 +7,8 @@ pub mod bucket_parser;
 pub mod bucket_search;
 pub mod custom_update;
 pub mod debug;
+pub mod dependencies;
+pub mod export;
 pub mod doctor;
 pub mod hold;
 pub mod info;
@@ -13,6 +16,7 @@ pub mod install;
 pub mod installed;
 pub mod linker;
 pub mod manifest;
+pub mod operation_history;
 pub mod powershell;
 pub mod scoop;
 pub mod search;
@@ -22,6 +26,7 @@ pub mod status;
 pub mod uninstall;
 pub mod update;
 pub mod update_config;
+pub mod update_log;
 pub mod updates;
 pub mod version;
 pub mod virustotal;
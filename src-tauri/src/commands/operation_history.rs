@@ -0,0 +1,117 @@
+//! Persistent history of package operations (install, uninstall, cleanup, ...).
+use crate::state::{ActiveOperation, AppState};
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+use tauri::{AppHandle, Manager, State};
+
+/// Settings key holding the list of recorded operations (newest last).
+const OPERATION_HISTORY_KEY: &str = "operationHistory";
+/// Maximum number of entries kept in the store.
+const MAX_HISTORY_ENTRIES: usize = 200;
+/// Number of entries returned when the caller does not pass a limit.
+const DEFAULT_HISTORY_LIMIT: usize = 50;
+
+/// A single recorded operation.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OperationRecord {
+    /// Unix timestamp (seconds) when the operation finished.
+    pub timestamp: u64,
+    /// Operation type, e.g. "install", "uninstall", "clear-cache", "cleanup".
+    pub op_type: String,
+    /// The package, bucket or cleanup scope the operation acted on.
+    pub target: String,
+    /// "success" or "failed".
+    pub result: String,
+    pub duration_ms: u64,
+    /// Error message for failed operations.
+    #[serde(default)]
+    pub error: Option<String>,
+}
+
+/// Summary of the last completed operation, shown by the UI on launch.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LastOperation {
+    pub op_type: String,
+    pub target: String,
+    pub success: bool,
+    /// Unix timestamp (seconds) when the operation finished.
+    pub timestamp: u64,
+}
+
+fn read_history(app: &AppHandle) -> Vec<OperationRecord> {
+    crate::commands::settings::read_config_list(app, OPERATION_HISTORY_KEY)
+}
+
+/// Records the outcome of an operation that was started at `started`.
+pub fn record_operation(
+    app: &AppHandle,
+    op_type: &str,
+    target: &str,
+    started: Instant,
+    result: &Result<(), String>,
+) {
+    if app.state::<AppState>().is_shutting_down() {
+        log::info!("Skipping operation history write during shutdown");
+        return;
+    }
+
+    let record = OperationRecord {
+        timestamp: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0),
+        op_type: op_type.to_string(),
+        target: target.to_string(),
+        result: if result.is_ok() { "success" } else { "failed" }.to_string(),
+        duration_ms: started.elapsed().as_millis() as u64,
+        error: result.as_ref().err().cloned(),
+    };
+
+    if let Err(e) = crate::commands::settings::append_config_list(
+        app,
+        OPERATION_HISTORY_KEY,
+        record,
+        MAX_HISTORY_ENTRIES,
+    ) {
+        log::warn!("Failed to write operation history entry: {}", e);
+    }
+}
+
+/// Returns the most recent operations, newest first, optionally filtered by operation type.
+#[tauri::command]
+pub fn get_operation_history(
+    app: AppHandle,
+    limit: Option<usize>,
+    filter_op_type: Option<String>,
+) -> Vec<OperationRecord> {
+    let limit = limit.unwrap_or(DEFAULT_HISTORY_LIMIT);
+    read_history(&app)
+        .into_iter()
+        .rev()
+        .filter(|entry| {
+            filter_op_type
+                .as_deref()
+                .map_or(true, |op_type| entry.op_type == op_type)
+        })
+        .take(limit)
+        .collect()
+}
+
+/// Returns the last completed operation, i.e. the newest history entry, if any.
+#[tauri::command]
+pub fn get_last_operation(app: AppHandle) -> Option<LastOperation> {
+    read_history(&app).pop().map(|record| LastOperation {
+        success: record.result == "success",
+        op_type: record.op_type,
+        target: record.target,
+        timestamp: record.timestamp,
+    })
+}
+
+/// Lists the package operations that are currently running, oldest first.
+#[tauri::command]
+pub fn get_active_operations(state: State<'_, AppState>) -> Vec<ActiveOperation> {
+    state.active_operations()
+}
@@ -1,22 +1,121 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
 use serde::Serialize;
+use std::io::Write;
 use std::process::Stdio;
-use tauri::{Emitter, Listener, Window};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::{Emitter, Listener, Manager, Window};
 use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::process::{Child, Command};
 use tokio::sync::{mpsc, oneshot};
+use tokio::task::JoinHandle;
 
 pub const EVENT_OUTPUT: &str = "operation-output";
 pub const EVENT_FINISHED: &str = "operation-finished";
 pub const EVENT_CANCEL: &str = "cancel-operation";
+pub const EVENT_DOWNLOAD_PROGRESS: &str = "download-progress";
+/// Emitted instead of `output_event` when output batching is enabled; carries `Vec<StreamOutput>`.
+pub const EVENT_OUTPUT_BATCH: &str = "operation-output-batch";
+
+/// Settings key for the default timeout (in seconds) of streamed operations. `0` disables it.
+pub const OPERATION_TIMEOUT_KEY: &str = "operations.timeoutSecs";
+/// Settings key that switches output from one event per line to coalesced batches.
+pub const BATCH_OUTPUT_KEY: &str = "operations.batchOutput";
+/// Lines received within this window are emitted together in batching mode.
+const OUTPUT_BATCH_WINDOW: Duration = Duration::from_millis(50);
+/// How long to wait for the remaining output to be forwarded once the process has ended.
+const OUTPUT_DRAIN_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Sequence number that keeps operation ids created within the same second apart.
+static OPERATION_SEQ: AtomicU64 = AtomicU64::new(0);
+
+/// Builds a unique operation id of the form `<prefix>-<unix secs>-<seq>`.
+pub fn new_operation_id(prefix: &str) -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let seq = OPERATION_SEQ.fetch_add(1, Ordering::Relaxed);
+    format!("{}-{}-{}", prefix, secs, seq)
+}
 
 /// Represents a line of output from a command, specifying its source (stdout or stderr).
 #[derive(Serialize, Clone)]
 pub struct StreamOutput {
     pub line: String,
     pub source: String,
+    /// Best-effort severity (`info`, `warn` or `error`) derived from Scoop's line prefixes.
+    pub level: String,
+    pub operation_id: Option<String>,
+    /// Unix timestamp (milliseconds) at which the line was received.
+    pub ts: u64,
+}
+
+impl StreamOutput {
+    /// Creates an output line stamped with the current time.
+    pub fn new(line: impl Into<String>, source: &str, operation_id: Option<String>) -> Self {
+        let line = line.into();
+        Self {
+            level: crate::commands::scoop::output::line_level(&line, source).to_string(),
+            line,
+            source: source.to_string(),
+            operation_id,
+            ts: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or(0),
+        }
+    }
+}
+
+/// Download progress parsed from a Scoop progress line.
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct DownloadProgress {
+    /// The package being downloaded, taken from the last "Installing/Updating 'name'" line.
+    pub package: Option<String>,
+    pub percent: u8,
+    pub bytes_done: Option<u64>,
+    pub bytes_total: Option<u64>,
     pub operation_id: Option<String>,
 }
 
+// Scoop prints progress as e.g. `app.zip (12.3 MB) [=====>    ]  45%`, redrawn with `\r`
+static PERCENT_RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(\d{1,3})%\s*$").unwrap());
+static SIZE_RE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\((\d+(?:\.\d+)?)\s*(B|KB|MB|GB)\)").unwrap());
+static PACKAGE_RE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?:Installing|Updating|Downloading new version of) '([^']+)'").unwrap()
+});
+
+/// Parses the percentage and, when present, the total size from a progress line.
+///
+/// Only the last `\r`-separated segment is considered, since Scoop redraws the bar in place.
+fn parse_download_progress(line: &str) -> Option<(u8, Option<u64>)> {
+    if !crate::commands::scoop::output::is_download_progress(line) {
+        return None;
+    }
+    let segment = line.rsplit('\r').find(|s| !s.trim().is_empty())?;
+    let percent: u8 = PERCENT_RE.captures(segment)?[1].parse().ok()?;
+    if percent > 100 {
+        return None;
+    }
+
+    let total = SIZE_RE.captures(segment).and_then(|caps| {
+        let value: f64 = caps[1].parse().ok()?;
+        let multiplier = match &caps[2] {
+            "KB" => 1024.0,
+            "MB" => 1024.0 * 1024.0,
+            "GB" => 1024.0 * 1024.0 * 1024.0,
+            _ => 1.0,
+        };
+        Some((value * multiplier) as u64)
+    });
+
+    Some((percent, total))
+}
+
 /// Represents the final result of a command, indicating success or failure and a corresponding message.
 #[derive(Serialize, Clone)]
 pub struct CommandResult {
@@ -45,6 +144,43 @@ pub fn create_powershell_command(command_str: &str) -> Command {
     cmd
 }
 
+/// Optional destinations for streamed output besides the per-line event.
+#[derive(Clone, Default)]
+struct OutputSinks {
+    /// Receives lines instead of `output_event` when batching is enabled.
+    batch_tx: Option<mpsc::UnboundedSender<StreamOutput>>,
+    /// Per-operation log file that receives every line.
+    log_file: Option<Arc<Mutex<std::fs::File>>>,
+}
+
+/// Tasks forwarding a command's output to the frontend.
+struct OutputTasks {
+    readers: Vec<JoinHandle<()>>,
+    batcher: Option<JoinHandle<()>>,
+}
+
+impl OutputTasks {
+    /// Waits until every output line, and the final batch, has been emitted, so that
+    /// nothing arrives after the finished event.
+    async fn drain(self) {
+        let drain = async {
+            for reader in self.readers {
+                let _ = reader.await;
+            }
+            // The readers held the only senders, so the batcher flushes and ends
+            if let Some(batcher) = self.batcher {
+                let _ = batcher.await;
+            }
+        };
+        if tokio::time::timeout(OUTPUT_DRAIN_TIMEOUT, drain)
+            .await
+            .is_err()
+        {
+            log::warn!("Timed out waiting for command output to be forwarded");
+        }
+    }
+}
+
 /// Spawns a task to read lines from a stream (stdout or stderr) and sends them to the frontend.
 ///
 /// It also sends any lines that indicate an error to the `error_tx` channel.
@@ -57,13 +193,40 @@ fn spawn_output_stream_handler(
     output_event: String,
     error_tx: mpsc::Sender<String>,
     operation_id: Option<String>,
-) {
+    sinks: OutputSinks,
+) -> JoinHandle<()> {
     let mut reader = BufReader::new(stream).lines();
 
     tokio::spawn(async move {
+        let mut current_package: Option<String> = None;
+        let mut last_percent: Option<u8> = None;
+
         while let Ok(Some(line)) = reader.next_line().await {
             // Log each line for debugging
             log::debug!("Output line [{}]: {}", source, line);
+
+            // Surface download progress separately so the UI can render a progress bar
+            if source == "stdout" {
+                if let Some(caps) = PACKAGE_RE.captures(line.trim_start()) {
+                    current_package = Some(caps[1].to_string());
+                    last_percent = None;
+                }
+                if let Some((percent, bytes_total)) = parse_download_progress(&line) {
+                    if last_percent != Some(percent) {
+                        last_percent = Some(percent);
+                        let progress = DownloadProgress {
+                            package: current_package.clone(),
+                            percent,
+                            bytes_done: bytes_total.map(|total| total * percent as u64 / 100),
+                            bytes_total,
+                            operation_id: operation_id.clone(),
+                        };
+                        if let Err(e) = window.emit(EVENT_DOWNLOAD_PROGRESS, progress) {
+                            log::error!("Failed to emit download progress: {}", e);
+                        }
+                    }
+                }
+            }
             
             // Enhanced error detection for scoop commands
             let is_error_line = source == "stderr"
@@ -86,21 +249,52 @@ fn spawn_output_stream_handler(
                 }
             }
 
+            if let Some(log_file) = &sinks.log_file {
+                if let Ok(mut file) = log_file.lock() {
+                    let prefix = if source == "stderr" { "[stderr] " } else { "" };
+                    let _ = writeln!(file, "{}{}", prefix, line);
+                }
+            }
+
             // Always send all lines to the frontend for display
-            if let Err(e) = window.emit(
-                &output_event,
-                StreamOutput {
-                    line: line.clone(),
-                    source: source.to_string(),
-                    operation_id: operation_id.clone(),
-                },
-            ) {
-                log::error!("Failed to emit output event for line '{}': {}", line, e);
+            let output = StreamOutput::new(line.clone(), source, operation_id.clone());
+            match &sinks.batch_tx {
+                Some(tx) => {
+                    let _ = tx.send(output);
+                }
+                None => {
+                    if let Err(e) = window.emit(&output_event, output) {
+                        log::error!("Failed to emit output event for line '{}': {}", line, e);
+                    }
+                }
             }
         }
         
         log::debug!("Output stream handler for {} ended", source);
-    });
+    })
+}
+
+/// Spawns a task that coalesces output lines arriving within `OUTPUT_BATCH_WINDOW`
+/// into single `operation-output-batch` events.
+///
+/// The task ends, after flushing, once every sender has been dropped.
+fn spawn_output_batcher(
+    window: Window,
+    mut batch_rx: mpsc::UnboundedReceiver<StreamOutput>,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        while let Some(first) = batch_rx.recv().await {
+            let mut batch = vec![first];
+            let deadline = tokio::time::Instant::now() + OUTPUT_BATCH_WINDOW;
+            while let Ok(Some(output)) = tokio::time::timeout_at(deadline, batch_rx.recv()).await {
+                batch.push(output);
+            }
+
+            if let Err(e) = window.emit(EVENT_OUTPUT_BATCH, &batch) {
+                log::error!("Failed to emit output batch: {}", e);
+            }
+        }
+    })
 }
 
 /// Sets up a listener for a cancellation event from the frontend.
@@ -124,9 +318,14 @@ fn setup_cancellation_handler(window: &Window, cancel_event: &str, cancel_tx: on
 
 /// Executes a long-running command and streams its output to the frontend.
 ///
-/// - Emits `output_event` with `StreamOutput` for each line of output.
+/// - Emits `output_event` with `StreamOutput` for each line of output, or
+///   `operation-output-batch` with a `Vec<StreamOutput>` when `operations.batchOutput` is set.
+/// - Emits `download-progress` with `DownloadProgress` when a stdout line carries a percentage.
+/// - Writes the combined output to `logs/operations/<operation_id>.log` when an id is given.
 /// - Emits `finished_event` with `CommandResult` when command completes.
 /// - Listens for `cancel_event` to terminate the process.
+/// - Kills the process once `timeout` elapses. When `None`, the `operations.timeoutSecs`
+///   setting is used; if that is unset or `0` the command may run indefinitely.
 pub async fn run_and_stream_command(
     window: Window,
     command_str: String,
@@ -135,12 +334,71 @@ pub async fn run_and_stream_command(
     finished_event: &str,
     cancel_event: &str,
     operation_id: Option<String>,
+    timeout: Option<Duration>,
+) -> Result<(), String> {
+    let display_str = command_str.clone();
+    stream_command(
+        window,
+        command_str,
+        &display_str,
+        operation_name,
+        output_event,
+        finished_event,
+        cancel_event,
+        operation_id,
+        timeout,
+    )
+    .await
+}
+
+/// Like [`run_and_stream_command`] with the default events, but logs `display_str`
+/// in place of the command so values embedded in it (such as secrets) stay out of
+/// the logs.
+pub async fn run_and_stream_redacted_command(
+    window: Window,
+    command_str: String,
+    display_str: &str,
+    operation_name: String,
+) -> Result<(), String> {
+    stream_command(
+        window,
+        command_str,
+        display_str,
+        operation_name,
+        EVENT_OUTPUT,
+        EVENT_FINISHED,
+        EVENT_CANCEL,
+        None,
+        None,
+    )
+    .await
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn stream_command(
+    window: Window,
+    command_str: String,
+    display_str: &str,
+    operation_name: String,
+    output_event: &str,
+    finished_event: &str,
+    cancel_event: &str,
+    operation_id: Option<String>,
+    timeout: Option<Duration>,
 ) -> Result<(), String> {
-    log::info!("Executing streaming command: {}", &command_str);
+    log::info!("Executing streaming command: {}", display_str);
+
+    let timeout = timeout.or_else(|| default_operation_timeout(&window));
+    let timeout_elapsed = async move {
+        match timeout {
+            Some(duration) => tokio::time::sleep(duration).await,
+            None => std::future::pending::<()>().await,
+        }
+    };
 
     let mut child = create_powershell_command(&command_str)
         .spawn()
-        .map_err(|e| format!("Failed to spawn command '{}': {}", command_str, e))?;
+        .map_err(|e| format!("Failed to spawn command '{}': {}", display_str, e))?;
 
     let stdout = child
         .stdout
@@ -156,33 +414,180 @@ pub async fn run_and_stream_command(
 
     setup_cancellation_handler(&window, cancel_event, cancel_tx);
 
-    spawn_output_stream_handler(
+    let (batch_tx, batcher) = if output_batching_enabled(&window) {
+        let (batch_tx, batch_rx) = mpsc::unbounded_channel::<StreamOutput>();
+        (
+            Some(batch_tx),
+            Some(spawn_output_batcher(window.clone(), batch_rx)),
+        )
+    } else {
+        (None, None)
+    };
+    let sinks = OutputSinks {
+        batch_tx,
+        log_file: operation_id
+            .as_deref()
+            .and_then(|id| open_operation_log(id, &operation_name)),
+    };
+
+    let stdout_reader = spawn_output_stream_handler(
         stdout,
         "stdout",
         window.clone(),
         output_event.to_string(),
         error_tx.clone(),
         operation_id.clone(),
+        sinks.clone(),
     );
-    spawn_output_stream_handler(
+    let stderr_reader = spawn_output_stream_handler(
         stderr,
         "stderr",
         window.clone(),
         output_event.to_string(),
         error_tx,
         operation_id.clone(),
+        sinks,
     );
+    let output = OutputTasks {
+        readers: vec![stdout_reader, stderr_reader],
+        batcher,
+    };
 
     tokio::select! {
         status_res = child.wait() => {
-            handle_command_completion(status_res, &operation_name, &window, finished_event, &mut error_rx, operation_id.clone()).await
+            handle_command_completion(status_res, &operation_name, &window, finished_event, &mut error_rx, operation_id.clone(), output).await
         },
         _ = cancel_rx => {
-            handle_cancellation(child, &operation_name, &window, finished_event, operation_id.clone()).await
+            handle_cancellation(child, &operation_name, &window, finished_event, operation_id.clone(), output).await
+        },
+        _ = timeout_elapsed => {
+            let message = format!(
+                "{} timed out after {} seconds",
+                operation_name,
+                timeout.map(|d| d.as_secs()).unwrap_or(0)
+            );
+            log::warn!("{}", message);
+            terminate_with_message(child, message, &window, finished_event, operation_id.clone(), output).await
+        }
+    }
+}
+
+/// Reads the default operation timeout from settings.
+fn default_operation_timeout(window: &Window) -> Option<Duration> {
+    crate::commands::settings::get_config_value(
+        window.app_handle().clone(),
+        OPERATION_TIMEOUT_KEY.to_string(),
+    )
+    .ok()
+    .flatten()
+    .and_then(|v| v.as_u64())
+    .filter(|secs| *secs > 0)
+    .map(Duration::from_secs)
+}
+
+/// Creates the log file that receives this operation's combined output.
+fn open_operation_log(
+    operation_id: &str,
+    operation_name: &str,
+) -> Option<Arc<Mutex<std::fs::File>>> {
+    let path = match crate::commands::debug::operation_log_path(operation_id) {
+        Ok(path) => path,
+        Err(e) => {
+            log::warn!("Failed to resolve operation log: {}", e);
+            return None;
+        }
+    };
+    if let Some(parent) = path.parent() {
+        if let Err(e) = std::fs::create_dir_all(parent) {
+            log::warn!("Failed to create operation log directory: {}", e);
+            return None;
+        }
+    }
+
+    // Append so a reused id never truncates the output of an earlier run
+    match std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+    {
+        Ok(mut file) => {
+            let _ = writeln!(
+                file,
+                "# {} ({})",
+                operation_name,
+                chrono::Local::now().to_rfc3339()
+            );
+            Some(Arc::new(Mutex::new(file)))
+        }
+        Err(e) => {
+            log::warn!("Failed to create operation log {}: {}", path.display(), e);
+            None
         }
     }
 }
 
+/// Runs a command without a window, appending every output line to the operation's
+/// log file as soon as it arrives so a crash mid-run still leaves the output on disk.
+///
+/// Returns the exit status along with the collected stdout and stderr.
+pub(crate) async fn run_command_with_operation_log(
+    command_str: &str,
+    operation_id: &str,
+) -> Result<(std::process::ExitStatus, String, String), String> {
+    let mut child = create_powershell_command(command_str)
+        .spawn()
+        .map_err(|e| format!("Failed to execute {}: {}", command_str, e))?;
+    let stdout = child
+        .stdout
+        .take()
+        .ok_or("Child process did not have a handle to stdout")?;
+    let stderr = child
+        .stderr
+        .take()
+        .ok_or("Child process did not have a handle to stderr")?;
+    let log_file = open_operation_log(operation_id, command_str);
+
+    let collect = |stream: Box<dyn AsyncRead + Unpin + Send>, prefix: &'static str| {
+        let log_file = log_file.clone();
+        async move {
+            let mut reader = BufReader::new(stream).lines();
+            let mut collected = String::new();
+            while let Ok(Some(line)) = reader.next_line().await {
+                if let Some(log_file) = &log_file {
+                    if let Ok(mut file) = log_file.lock() {
+                        let _ = writeln!(file, "{}{}", prefix, line);
+                    }
+                }
+                collected.push_str(&line);
+                collected.push('\n');
+            }
+            collected
+        }
+    };
+
+    let (stdout, stderr) = tokio::join!(
+        collect(Box::new(stdout), ""),
+        collect(Box::new(stderr), "[stderr] ")
+    );
+    let status = child
+        .wait()
+        .await
+        .map_err(|e| format!("Failed to wait for {}: {}", command_str, e))?;
+    Ok((status, stdout, stderr))
+}
+
+/// Returns true if output lines should be batched (`operations.batchOutput`, off by default).
+fn output_batching_enabled(window: &Window) -> bool {
+    crate::commands::settings::get_config_value(
+        window.app_handle().clone(),
+        BATCH_OUTPUT_KEY.to_string(),
+    )
+    .ok()
+    .flatten()
+    .and_then(|v| v.as_bool())
+    .unwrap_or(false)
+}
+
 /// Handles the completion of the command, checking for errors and emitting the final result.
 async fn handle_command_completion(
     status_res: Result<std::process::ExitStatus, std::io::Error>,
@@ -191,6 +596,7 @@ async fn handle_command_completion(
     finished_event: &str,
     error_rx: &mut mpsc::Receiver<String>,
     operation_id: Option<String>,
+    output: OutputTasks,
 ) -> Result<(), String> {
     let status = status_res.map_err(|e| {
         format!(
@@ -199,6 +605,7 @@ async fn handle_command_completion(
         )
     })?;
     log::info!("{} finished with status: {}", operation_name, status);
+    output.drain().await;
 
     // Collect all error messages
     let mut error_messages = Vec::new();
@@ -261,29 +668,43 @@ async fn handle_command_completion(
 
 /// Handles the cancellation of the command, killing the process and emitting a cancellation message.
 async fn handle_cancellation(
-    mut child: Child,
+    child: Child,
     operation_name: &str,
     window: &Window,
     finished_event: &str,
     operation_id: Option<String>,
+    output: OutputTasks,
 ) -> Result<(), String> {
     log::warn!("Cancelling operation: {}", operation_name);
 
+    let message = format!("{} was cancelled by user", operation_name);
+    terminate_with_message(child, message, window, finished_event, operation_id, output).await
+}
+
+/// Kills the child process and emits a failed `CommandResult` carrying `message`.
+async fn terminate_with_message(
+    mut child: Child,
+    message: String,
+    window: &Window,
+    finished_event: &str,
+    operation_id: Option<String>,
+    output: OutputTasks,
+) -> Result<(), String> {
     // Try to kill the process
     if let Err(e) = child.kill().await {
         log::error!("Failed to kill child process: {}", e);
     }
+    output.drain().await;
 
-    let message = format!("{} was cancelled by user", operation_name);
     if let Err(e) = window.emit(
         finished_event,
         CommandResult {
             success: false,
             message: message.clone(),
-            operation_id: operation_id.clone(),
+            operation_id,
         },
     ) {
-        log::error!("Failed to emit cancellation event: {}", e);
+        log::error!("Failed to emit termination event: {}", e);
     }
 
     Err(message)
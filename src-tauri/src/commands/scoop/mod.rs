@@ -0,0 +1,276 @@
+pub mod output;
+
+use super::powershell::{self, EVENT_CANCEL, EVENT_FINISHED, EVENT_OUTPUT};
+use crate::state::AppState;
+use std::path::PathBuf;
+use tauri::{Runtime, Window};
+
+/// Settings key making package operations act on globally installed apps (`--global`).
+pub const USE_GLOBAL_KEY: &str = "scoop.useGlobal";
+
+/// Defines the supported Scoop operations.
+#[derive(Debug, Clone, Copy)]
+pub enum ScoopOp {
+    Install,
+    Uninstall,
+    Update,
+    UpdateForce,
+    ClearCache,
+    UpdateAll,
+    Reset,
+    Hold,
+    Unhold,
+    BucketAdd,
+    BucketRemove,
+    Checkup,
+}
+
+/// Validates a version string before it is interpolated into a Scoop command.
+///
+/// Only characters that appear in real-world Scoop versions are allowed, which
+/// rules out quotes, whitespace and PowerShell metacharacters.
+pub fn validate_version(version: &str) -> Result<(), String> {
+    let is_valid = !version.is_empty()
+        && version.len() <= 64
+        && version
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_' | '+'));
+
+    if is_valid {
+        Ok(())
+    } else {
+        Err(format!("Invalid version string: '{}'", version))
+    }
+}
+
+/// Whether Scoop accepts `--global` for the operation.
+fn supports_global(op: ScoopOp) -> bool {
+    matches!(
+        op,
+        ScoopOp::Install
+            | ScoopOp::Uninstall
+            | ScoopOp::Update
+            | ScoopOp::UpdateForce
+            | ScoopOp::UpdateAll
+            | ScoopOp::Reset
+            | ScoopOp::Hold
+            | ScoopOp::Unhold
+    )
+}
+
+/// Checks that global operations can run in this process.
+///
+/// Scoop refuses to touch global apps without administrator rights, and Rscoop cannot
+/// elevate a running PowerShell session, so the app itself has to be run as admin.
+pub(crate) fn ensure_global_supported() -> Result<(), String> {
+    #[cfg(windows)]
+    {
+        use windows_sys::Win32::UI::Shell::IsUserAnAdmin;
+
+        if unsafe { IsUserAnAdmin() } != 0 {
+            Ok(())
+        } else {
+            Err("Global Scoop operations require administrator rights. \
+                 Restart Rscoop as administrator or disable global mode."
+                .to_string())
+        }
+    }
+    #[cfg(not(windows))]
+    {
+        Err("Global Scoop operations are only supported on Windows".to_string())
+    }
+}
+
+/// Reads the `scoop.useGlobal` setting.
+pub(crate) fn global_enabled<R: Runtime>(app: &tauri::AppHandle<R>) -> bool {
+    crate::commands::settings::get_config_value(app.clone(), USE_GLOBAL_KEY.to_string())
+        .ok()
+        .flatten()
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
+
+/// Reads the `scoop.useGlobal` setting for `op`, validating that global operations are
+/// possible when it is enabled. Always `false` for operations without `--global`.
+pub(crate) fn use_global<R: Runtime>(
+    app: &tauri::AppHandle<R>,
+    op: ScoopOp,
+) -> Result<bool, String> {
+    if !supports_global(op) {
+        return Ok(false);
+    }
+    let global = global_enabled(app);
+    if global {
+        ensure_global_supported()?;
+    }
+    Ok(global)
+}
+
+/// Root of the global Scoop installation, resolved the way Scoop resolves `$globaldir`:
+/// `$env:SCOOP_GLOBAL`, then the `global_path` config value, then `%ProgramData%\scoop`.
+pub(crate) fn global_root() -> PathBuf {
+    if let Some(path) = std::env::var_os("SCOOP_GLOBAL").filter(|p| !p.is_empty()) {
+        return PathBuf::from(path);
+    }
+    let configured = crate::commands::settings::get_scoop_config()
+        .ok()
+        .flatten()
+        .and_then(|config| config.get("global_path")?.as_str().map(PathBuf::from));
+    configured.unwrap_or_else(|| {
+        PathBuf::from(std::env::var_os("ProgramData").unwrap_or_else(|| "C:\\ProgramData".into()))
+            .join("scoop")
+    })
+}
+
+/// The Scoop root holding the apps that package operations act on: the global root
+/// when `scoop.useGlobal` is enabled, otherwise the per-user root.
+///
+/// Buckets and the download cache always live in the per-user root.
+pub(crate) fn apps_root<R: Runtime>(app: &tauri::AppHandle<R>, state: &AppState) -> PathBuf {
+    if global_enabled(app) {
+        global_root()
+    } else {
+        state.scoop_path()
+    }
+}
+
+/// Builds a Scoop command as a string, returning an error if a required
+/// package name is missing.
+///
+/// For the bucket operations `package` is the bucket name and `bucket` is the
+/// optional repository URL. `global` appends `--global` to operations that support it.
+fn build_scoop_cmd(
+    op: ScoopOp,
+    package: Option<&str>,
+    bucket: Option<&str>,
+    global: bool,
+) -> Result<String, String> {
+    let mut command = match op {
+        ScoopOp::Install => {
+            let pkg = package.ok_or("A package name is required to install.")?;
+            match bucket {
+                Some(b) => format!("scoop install {}/{}", b, pkg),
+                None => format!("scoop install {}", pkg),
+            }
+        }
+        ScoopOp::Uninstall => {
+            let pkg = package.ok_or("A package name is required to uninstall.")?;
+            format!("scoop uninstall {}", pkg)
+        }
+        ScoopOp::Update => {
+            let pkg = package.ok_or("A package name is required to update.")?;
+            format!("scoop update {}", pkg)
+        }
+        ScoopOp::UpdateForce => {
+            let pkg = package.ok_or("A package name is required to force update.")?;
+            format!("scoop update {} --force", pkg)
+        }
+        ScoopOp::ClearCache => {
+            let pkg = package.ok_or("A package name is required to clear the cache.")?;
+            // Scoop matches cache files against `^<app>#`, and cache files are named
+            // `name#version#hash`, so `name#version` narrows removal to a single version.
+            match pkg.split_once('@') {
+                Some((name, version)) => format!("scoop cache rm '{}#{}'", name, version),
+                None => format!("scoop cache rm {}", pkg),
+            }
+        }
+        ScoopOp::UpdateAll => "scoop update *".to_string(),
+        ScoopOp::Reset => {
+            let pkg = package.ok_or("A package name is required to reset.")?;
+            format!("scoop reset {}", pkg)
+        }
+        ScoopOp::Hold => {
+            let pkg = package.ok_or("A package name is required to hold.")?;
+            format!("scoop hold {}", pkg)
+        }
+        ScoopOp::Unhold => {
+            let pkg = package.ok_or("A package name is required to unhold.")?;
+            format!("scoop unhold {}", pkg)
+        }
+        ScoopOp::BucketAdd => {
+            let name = package.ok_or("A bucket name is required to add a bucket.")?;
+            match bucket {
+                Some(url) => format!("scoop bucket add {} '{}'", name, url),
+                None => format!("scoop bucket add {}", name),
+            }
+        }
+        ScoopOp::BucketRemove => {
+            let name = package.ok_or("A bucket name is required to remove a bucket.")?;
+            format!("scoop bucket rm {}", name)
+        }
+        ScoopOp::Checkup => "scoop checkup".to_string(),
+    };
+
+    if global && supports_global(op) {
+        command.push_str(" --global");
+    }
+
+    Ok(command)
+}
+
+/// Executes a Scoop operation and streams the output to the frontend.
+///
+/// This function builds the Scoop command, creates a human-friendly operation
+/// name for the UI, and then executes it using the PowerShell runner.
+pub async fn execute_scoop(
+    window: Window,
+    op: ScoopOp,
+    package: Option<&str>,
+    bucket: Option<&str>,
+    operation_id: Option<String>,
+    global: bool,
+) -> Result<(), String> {
+    execute_scoop_with_finished_event(
+        window,
+        op,
+        package,
+        bucket,
+        operation_id,
+        global,
+        EVENT_FINISHED,
+    )
+    .await
+}
+
+/// Like `execute_scoop`, but reports completion on `finished_event` instead of
+/// `operation-finished`.
+pub async fn execute_scoop_with_finished_event(
+    window: Window,
+    op: ScoopOp,
+    package: Option<&str>,
+    bucket: Option<&str>,
+    operation_id: Option<String>,
+    global: bool,
+    finished_event: &str,
+) -> Result<(), String> {
+    let cmd = build_scoop_cmd(op, package, bucket, global)?;
+
+    let op_name = match (op, package) {
+        (ScoopOp::Install, Some(pkg)) => format!("Installing {}", pkg),
+        (ScoopOp::Uninstall, Some(pkg)) => format!("Uninstalling {}", pkg),
+        (ScoopOp::Update, Some(pkg)) => format!("Updating {}", pkg),
+        (ScoopOp::UpdateForce, Some(pkg)) => format!("Force updating {}", pkg),
+        (ScoopOp::ClearCache, Some(pkg)) => format!("Clearing cache for {}", pkg),
+        (ScoopOp::UpdateAll, _) => "Updating all packages".to_string(),
+        (ScoopOp::Reset, Some(pkg)) => format!("Resetting {}", pkg),
+        (ScoopOp::Hold, Some(pkg)) => format!("Holding {}", pkg),
+        (ScoopOp::Unhold, Some(pkg)) => format!("Unholding {}", pkg),
+        (ScoopOp::BucketAdd, Some(name)) => format!("Adding bucket {}", name),
+        (ScoopOp::BucketRemove, Some(name)) => format!("Removing bucket {}", name),
+        (ScoopOp::Checkup, _) => "Running scoop checkup".to_string(),
+        // This case should not be reached if `build_scoop_cmd` is correct.
+        _ => return Err("Invalid operation or missing package name.".to_string()),
+    };
+
+    powershell::run_and_stream_command(
+        window,
+        cmd,
+        op_name,
+        EVENT_OUTPUT,
+        finished_event,
+        EVENT_CANCEL,
+        operation_id,
+        None,
+    )
+    .await
+}
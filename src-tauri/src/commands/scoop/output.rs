@@ -0,0 +1,124 @@
+//! Matchers for Scoop's console output.
+//!
+//! Scoop's messages are plain English strings that may change between versions or
+//! be localized in the future, so every phrase check lives here instead of being
+//! spread across callers as inline `contains` calls.
+
+/// Returns true if the line reports that a package (or Scoop itself) is already current.
+///
+/// Examples: `'git' (2.44.0) is already installed.`,
+/// `Latest versions for all apps are installed!`, `Scoop is up to date.`
+pub fn is_up_to_date(line: &str) -> bool {
+    let line = line.trim().to_lowercase();
+    line.contains("is already installed")
+        || line.contains("latest versions for all apps are installed")
+        || line.contains("(latest version)")
+        || line.contains("is up to date")
+        || line.contains("already up to date")
+}
+
+/// Returns true if the line reports a successful install or update of a package.
+///
+/// Examples: `'git' (2.45.0) was installed successfully!`, `Scoop was updated successfully!`
+pub fn is_updated(line: &str) -> bool {
+    let line = line.trim().to_lowercase();
+    line.contains("was installed successfully") || line.contains("was updated successfully")
+}
+
+/// Returns true if the line is a download progress bar ending in a percentage.
+///
+/// Example: `git.7z (52.3 MB) [=================>   ]  87%`
+pub fn is_download_progress(line: &str) -> bool {
+    let segment = line
+        .rsplit('\r')
+        .find(|s| !s.trim().is_empty())
+        .unwrap_or("");
+    let segment = segment.trim_end();
+    match segment.strip_suffix('%') {
+        Some(rest) => {
+            let digits: String = rest
+                .chars()
+                .rev()
+                .take_while(|c| c.is_ascii_digit())
+                .collect();
+            !digits.is_empty() && digits.len() <= 3
+        }
+        None => false,
+    }
+}
+
+/// Returns the severity of an output line: `"error"`, `"warn"` or `"info"`.
+///
+/// Scoop prints its warnings and errors to stdout with `WARN` and `ERROR` prefixes,
+/// so those are matched first; other stderr lines count as errors.
+pub fn line_level(line: &str, source: &str) -> &'static str {
+    let line = line.trim_start();
+    if line.starts_with("ERROR") {
+        "error"
+    } else if line.starts_with("WARN") {
+        "warn"
+    } else if source == "stderr" {
+        "error"
+    } else {
+        "info"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_up_to_date_lines() {
+        assert!(is_up_to_date("WARN  'git' (2.44.0) is already installed."));
+        assert!(is_up_to_date(
+            "Latest versions for all apps are installed! For more information try 'scoop status'"
+        ));
+        assert!(is_up_to_date("Scoop is up to date."));
+        assert!(is_up_to_date("git: 2.44.0 (latest version)"));
+        assert!(!is_up_to_date("Updating 'git' (2.44.0 -> 2.45.0)"));
+    }
+
+    #[test]
+    fn classifies_line_levels() {
+        assert_eq!(
+            line_level("WARN  'git' (2.44.0) is already installed.", "stdout"),
+            "warn"
+        );
+        assert_eq!(
+            line_level("ERROR 'foo' isn't installed.", "stdout"),
+            "error"
+        );
+        assert_eq!(
+            line_level("Installing 'git' (2.45.0) [64bit]", "stdout"),
+            "info"
+        );
+        assert_eq!(
+            line_level("Access to the path is denied.", "stderr"),
+            "error"
+        );
+    }
+
+    #[test]
+    fn detects_updated_lines() {
+        assert!(is_updated("'git' (2.45.0) was installed successfully!"));
+        assert!(is_updated("Scoop was updated successfully!"));
+        assert!(!is_updated("Updating 'git' (2.44.0 -> 2.45.0)"));
+        assert!(!is_updated("'git' (2.44.0) is already installed."));
+    }
+
+    #[test]
+    fn detects_download_progress_lines() {
+        assert!(is_download_progress(
+            "git.7z (52.3 MB) [=================>   ]  87%"
+        ));
+        assert!(is_download_progress(
+            "git.7z (52.3 MB) [>    ]   1%\rgit.7z (52.3 MB) [=====]  100%"
+        ));
+        assert!(!is_download_progress(
+            "Downloading https://example.com/git.7z (52.3 MB)..."
+        ));
+        assert!(!is_download_progress("Checking hash of git.7z ... ok."));
+        assert!(!is_download_progress("Progress: %"));
+    }
+}
@@ -6,15 +6,32 @@ use once_cell::sync::Lazy;
 use rayon::prelude::*;
 use regex::Regex;
 use serde_json::Value;
+use serde::Serialize;
 use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use tauri::Manager;
 use tokio::sync::Mutex;
 
 // Global cache for manifest paths to avoid re-scanning the filesystem on every search.
 static MANIFEST_CACHE: Lazy<Mutex<Option<HashSet<PathBuf>>>> = Lazy::new(|| Mutex::new(None));
 
+// Counters for diagnosing cache behaviour; reset whenever the cache is invalidated.
+static CACHE_HITS: AtomicU64 = AtomicU64::new(0);
+static CACHE_MISSES: AtomicU64 = AtomicU64::new(0);
+// Unix timestamp (seconds) of the last invalidation, 0 if never invalidated.
+static LAST_INVALIDATED: AtomicU64 = AtomicU64::new(0);
+
+/// Statistics about the manifest cache.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct ManifestCacheStats {
+    pub entries: usize,
+    pub hits: u64,
+    pub misses: u64,
+    pub last_invalidated: Option<u64>,
+}
+
 /// Finds all `.json` manifest files in a given bucket's `bucket` subdirectory.
 fn find_manifests_in_bucket(bucket_path: PathBuf) -> Vec<PathBuf> {
     let manifests_path = bucket_path.join("bucket");
@@ -55,20 +72,27 @@ async fn populate_manifest_cache(scoop_path: &Path) -> Result<HashSet<PathBuf>,
 }
 
 /// Acquires a lock on the manifest cache and populates it if it's empty.
-async fn get_manifests<R: tauri::Runtime>(
+pub(crate) async fn get_manifests<R: tauri::Runtime>(
     app: tauri::AppHandle<R>,
 ) -> Result<(HashSet<PathBuf>, bool), String> {
+    let state = app.state::<AppState>();
+    get_manifests_from_path(&state.scoop_path()).await
+}
+
+/// Returns the cached manifest paths, scanning `scoop_path` on a cache miss.
+/// The boolean is true when the cache was cold.
+async fn get_manifests_from_path(scoop_path: &Path) -> Result<(HashSet<PathBuf>, bool), String> {
     let mut guard = MANIFEST_CACHE.lock().await;
     let is_cold = guard.is_none();
 
     if is_cold {
         log::info!("Cold search: Populating manifest cache.");
-        let state = app.state::<AppState>();
-        let scoop_path = state.scoop_path();
-        let paths = populate_manifest_cache(&scoop_path).await?;
+        CACHE_MISSES.fetch_add(1, Ordering::Relaxed);
+        let paths = populate_manifest_cache(scoop_path).await?;
         *guard = Some(paths.clone());
         Ok((paths, true))
     } else {
+        CACHE_HITS.fetch_add(1, Ordering::Relaxed);
         Ok((guard.as_ref().unwrap().clone(), false))
     }
 }
@@ -115,6 +139,70 @@ fn build_search_regex(term: &str) -> Result<Regex, String> {
     Regex::new(&pattern_str).map_err(|e| e.to_string())
 }
 
+/// Matches the given manifests against a search term, by package name first and
+/// then by the binaries declared in each manifest.
+fn search_packages(manifest_paths: &HashSet<PathBuf>, term: &str) -> Result<Vec<ScoopPackage>, String> {
+    let pattern = build_search_regex(term)?;
+
+    Ok(manifest_paths
+        .par_iter()
+        .filter_map(|path| {
+            // Check if the file name (package name) matches first
+            let file_name = path.file_stem().and_then(|s| s.to_str())?;
+            let name_matches = pattern.is_match(file_name);
+
+            // Determine if the search term matches one of the binaries declared in the manifest.
+            // We only do this expensive parse if the package name itself did **not** match.
+            let match_source = if name_matches {
+                MatchSource::Name
+            } else {
+                // Load and inspect the manifest's `bin` field
+                let content = std::fs::read_to_string(path).ok()?;
+                let json: Value = serde_json::from_str(&content).ok()?;
+
+                let does_bin_match = json.get("bin").map_or(false, |bin_val| {
+                    match bin_val {
+                        Value::String(s) => pattern.is_match(s),
+                        Value::Array(arr) => arr.iter().any(|entry| match entry {
+                            Value::String(s) => pattern.is_match(s),
+                            Value::Object(obj) => {
+                                // Some manifests use object syntax { "alias": "path/to/file" }
+                                obj.keys().any(|k| pattern.is_match(k))
+                                    || obj.values().any(|v| {
+                                        v.as_str().map_or(false, |s| pattern.is_match(s))
+                                    })
+                            }
+                            _ => false,
+                        }),
+                        Value::Object(obj) => {
+                            // Very uncommon, but treat similarly to array/object case
+                            obj.keys().any(|k| pattern.is_match(k))
+                                || obj
+                                    .values()
+                                    .any(|v| v.as_str().map_or(false, |s| pattern.is_match(s)))
+                        }
+                        _ => false,
+                    }
+                });
+
+                if does_bin_match {
+                    MatchSource::Binary
+                } else {
+                    MatchSource::None
+                }
+            };
+
+            if match_source == MatchSource::None {
+                return None;
+            }
+
+            let mut pkg = parse_package_from_manifest(path)?;
+            pkg.match_source = match_source;
+            Some(pkg)
+        })
+        .collect())
+}
+
 /// Searches for Scoop packages based on a search term.
 #[tauri::command]
 pub async fn search_scoop<R: tauri::Runtime>(
@@ -129,6 +217,8 @@ pub async fn search_scoop<R: tauri::Runtime>(
     let search_start = std::time::Instant::now();
 
     let (manifest_paths, is_cold) = get_manifests(app.clone()).await?;
+    let manifest_count = manifest_paths.len();
+    let term_clone = term.clone();
     let cache_time = search_start.elapsed();
 
     if is_cold {
@@ -139,76 +229,14 @@ pub async fn search_scoop<R: tauri::Runtime>(
     } else {
         log::info!(
             "search_scoop: ✓ Using pre-warmed manifest cache ({} manifests, retrieved in {:.2}ms)",
-            manifest_paths.len(),
+            manifest_count,
             cache_time.as_millis()
         );
     }
 
-    let pattern = build_search_regex(&term)?;
-
-    let manifest_paths_clone = manifest_paths.clone();
-
-    let mut packages: Vec<ScoopPackage> = tokio::task::spawn_blocking(move || {
-        manifest_paths_clone
-            .par_iter()
-            .filter_map(|path| {
-                // Check if the file name (package name) matches first
-                let file_name = path.file_stem().and_then(|s| s.to_str())?;
-                let name_matches = pattern.is_match(file_name);
-
-                // Determine if the search term matches one of the binaries declared in the manifest.
-                // We only do this expensive parse if the package name itself did **not** match.
-                let match_source = if name_matches {
-                    MatchSource::Name
-                } else {
-                    // Load and inspect the manifest's `bin` field
-                    let content = std::fs::read_to_string(path).ok()?;
-                    let json: Value = serde_json::from_str(&content).ok()?;
-
-                    let does_bin_match = json.get("bin").map_or(false, |bin_val| {
-                        match bin_val {
-                            Value::String(s) => pattern.is_match(s),
-                            Value::Array(arr) => arr.iter().any(|entry| match entry {
-                                Value::String(s) => pattern.is_match(s),
-                                Value::Object(obj) => {
-                                    // Some manifests use object syntax { "alias": "path/to/file" }
-                                    obj.keys().any(|k| pattern.is_match(k))
-                                        || obj.values().any(|v| {
-                                            v.as_str().map_or(false, |s| pattern.is_match(s))
-                                        })
-                                }
-                                _ => false,
-                            }),
-                            Value::Object(obj) => {
-                                // Very uncommon, but treat similarly to array/object case
-                                obj.keys().any(|k| pattern.is_match(k))
-                                    || obj
-                                        .values()
-                                        .any(|v| v.as_str().map_or(false, |s| pattern.is_match(s)))
-                            }
-                            _ => false,
-                        }
-                    });
-
-                    if does_bin_match {
-                        MatchSource::Binary
-                    } else {
-                        MatchSource::None
-                    }
-                };
-
-                if match_source == MatchSource::None {
-                    return None;
-                }
-
-                let mut pkg = parse_package_from_manifest(path)?;
-                pkg.match_source = match_source;
-                Some(pkg)
-            })
-            .collect()
-    })
-    .await
-    .map_err(|e| e.to_string())?;
+    let mut packages = tokio::task::spawn_blocking(move || search_packages(&manifest_paths, &term_clone))
+        .await
+        .map_err(|e| e.to_string())??;
 
     // Determine which of the found packages are already installed.
     let state = app.state::<AppState>();
@@ -275,5 +303,67 @@ pub async fn warm_manifest_cache<R: tauri::Runtime>(
 pub async fn invalidate_manifest_cache() {
     let mut guard = MANIFEST_CACHE.lock().await;
     *guard = None;
+    CACHE_HITS.store(0, Ordering::Relaxed);
+    CACHE_MISSES.store(0, Ordering::Relaxed);
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    LAST_INVALIDATED.store(now, Ordering::Relaxed);
     log::info!("Manifest cache invalidated.");
 }
+
+/// Returns statistics about the manifest cache.
+#[tauri::command]
+pub async fn get_manifest_cache_stats() -> Result<ManifestCacheStats, String> {
+    let guard = MANIFEST_CACHE.lock().await;
+    let last_invalidated = LAST_INVALIDATED.load(Ordering::Relaxed);
+    Ok(ManifestCacheStats {
+        entries: guard.as_ref().map(|paths| paths.len()).unwrap_or(0),
+        hits: CACHE_HITS.load(Ordering::Relaxed),
+        misses: CACHE_MISSES.load(Ordering::Relaxed),
+        last_invalidated: if last_invalidated == 0 { None } else { Some(last_invalidated) },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_invalidate_manifest_cache_clears_entries_and_stats() {
+        let scoop_dir = tempfile::tempdir().unwrap();
+        let bucket_dir = scoop_dir.path().join("buckets").join("main").join("bucket");
+        fs::create_dir_all(&bucket_dir).unwrap();
+        fs::write(
+            bucket_dir.join("foo.json"),
+            r#"{ "version": "1.0.0", "bin": "foo.exe" }"#,
+        )
+        .unwrap();
+
+        invalidate_manifest_cache().await;
+
+        let (paths, is_cold) = get_manifests_from_path(scoop_dir.path()).await.unwrap();
+        assert!(is_cold);
+        let (_, is_cold) = get_manifests_from_path(scoop_dir.path()).await.unwrap();
+        assert!(!is_cold);
+
+        let results = search_packages(&paths, "foo").unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name, "foo");
+        assert_eq!(results[0].source, "main");
+
+        let stats = get_manifest_cache_stats().await.unwrap();
+        assert_eq!(stats.entries, 1);
+        assert_eq!(stats.hits, 1);
+        assert_eq!(stats.misses, 1);
+
+        invalidate_manifest_cache().await;
+
+        let stats = get_manifest_cache_stats().await.unwrap();
+        assert_eq!(stats.entries, 0);
+        assert_eq!(stats.hits, 0);
+        assert_eq!(stats.misses, 0);
+        assert!(stats.last_invalidated.is_some());
+    }
+}
@@ -1,7 +1,10 @@
 //! Commands for reading and writing application settings from the persistent store.
+use serde::de::DeserializeOwned;
+use serde::Serialize;
 use serde_json::{Map, Value};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Once};
 use tauri::{AppHandle, Runtime, Manager};
 use tauri_plugin_store::{Store, StoreExt};
 use aes_gcm::{Aes256Gcm, Key, Nonce};
@@ -82,15 +85,143 @@ where
 {
     // Attempt migration from legacy store if needed
     migrate_from_legacy_store(&app);
+    ensure_store_file_valid(&app);
     
-    let store = app
-        .store(PathBuf::from(STORE_PATH))
-        .map_err(|e| e.to_string())?;
+    let store = open_settings_store(&app)?;
     let result = operation(&store);
-    store.save().map_err(|e| e.to_string())?;
+    save_store_atomically(&app, &store)?;
     Ok(result)
 }
 
+/// Opens the settings store with the plugin's debounced auto-save disabled, so every
+/// write reaches disk through `save_store_atomically` instead of an in-place rewrite.
+pub(crate) fn open_settings_store<R: Runtime>(app: &AppHandle<R>) -> Result<Arc<Store<R>>, String> {
+    open_store_at(app, Path::new(STORE_PATH))
+}
+
+/// Opens the store file at `path` without auto-save. Relative paths resolve against the
+/// app data directory.
+fn open_store_at<R: Runtime>(app: &AppHandle<R>, path: &Path) -> Result<Arc<Store<R>>, String> {
+    app.store_builder(path)
+        .disable_auto_save()
+        .build()
+        .map_err(|e| e.to_string())
+}
+
+/// Saves the settings store after the frontend changed it.
+///
+/// The frontend loads the store without auto-save, so its writes are persisted by
+/// this command rather than by the plugin.
+#[tauri::command]
+pub fn save_settings_store<R: Runtime>(app: AppHandle<R>) -> Result<(), String> {
+    let store = open_settings_store(&app)?;
+    save_store_atomically(&app, &store)
+}
+
+/// Unloads the settings store before the app exits.
+///
+/// The store plugin saves every loaded store on exit by rewriting the file in place;
+/// all changes have already been saved atomically, so the store is dropped instead.
+pub(crate) fn release_settings_store<R: Runtime>(app: &AppHandle<R>) {
+    if let Some(store) = app.get_store(PathBuf::from(STORE_PATH)) {
+        store.close_resource();
+    }
+}
+
+/// Persists the store through a temp file and rename so a crash mid-write can
+/// never leave a truncated settings file behind. Falls back to the plugin's own
+/// save if the app data directory cannot be resolved.
+fn save_store_atomically<R: Runtime>(app: &AppHandle<R>, store: &Store<R>) -> Result<(), String> {
+    let store_path = match app.path().app_data_dir() {
+        Ok(dir) => dir.join(STORE_PATH),
+        Err(_) => return store.save().map_err(|e| e.to_string()),
+    };
+
+    let entries: Map<String, Value> = store.entries().into_iter().collect();
+    let content = serde_json::to_vec_pretty(&Value::Object(entries))
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+    write_atomic_with_backup(&store_path, &content)
+}
+
+/// Writes `content` to `path` atomically, keeping the previous good copy as `<path>.bak`.
+fn write_atomic_with_backup(path: &Path, content: &[u8]) -> Result<(), String> {
+    use std::io::Write;
+
+    let parent = path
+        .parent()
+        .ok_or_else(|| format!("Invalid settings path: {}", path.display()))?;
+    fs::create_dir_all(parent)
+        .map_err(|e| format!("Failed to create settings directory: {}", e))?;
+
+    let tmp_path = path.with_extension("json.tmp");
+    {
+        let mut file = fs::File::create(&tmp_path)
+            .map_err(|e| format!("Failed to create {}: {}", tmp_path.display(), e))?;
+        file.write_all(content)
+            .map_err(|e| format!("Failed to write {}: {}", tmp_path.display(), e))?;
+        file.sync_all()
+            .map_err(|e| format!("Failed to flush {}: {}", tmp_path.display(), e))?;
+    }
+
+    // Only back up a copy that still parses, so a corrupt file never replaces a good backup
+    if is_valid_json_file(path) {
+        if let Err(e) = fs::copy(path, backup_path(path)) {
+            log::warn!("Failed to back up settings file: {}", e);
+        }
+    }
+
+    fs::rename(&tmp_path, path)
+        .map_err(|e| format!("Failed to replace {}: {}", path.display(), e))
+}
+
+fn backup_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".bak");
+    PathBuf::from(name)
+}
+
+fn is_valid_json_file(path: &Path) -> bool {
+    fs::read(path)
+        .ok()
+        .and_then(|bytes| serde_json::from_slice::<Value>(&bytes).ok())
+        .is_some()
+}
+
+/// Restores `path` from its `.bak` copy when the main file exists but cannot be parsed.
+/// Returns true if the backup was restored.
+fn restore_from_backup_if_corrupt(path: &Path) -> bool {
+    if !path.exists() || is_valid_json_file(path) {
+        return false;
+    }
+
+    let backup = backup_path(path);
+    if !is_valid_json_file(&backup) {
+        log::error!("Settings file {} is corrupt and no valid backup exists", path.display());
+        return false;
+    }
+
+    match fs::copy(&backup, path) {
+        Ok(_) => {
+            log::warn!("Settings file {} was corrupt, restored from backup", path.display());
+            true
+        }
+        Err(e) => {
+            log::error!("Failed to restore settings from backup: {}", e);
+            false
+        }
+    }
+}
+
+/// Checks the settings file once per process, before the store first loads it.
+fn ensure_store_file_valid<R: Runtime>(app: &AppHandle<R>) {
+    static CHECKED: Once = Once::new();
+    CHECKED.call_once(|| {
+        if let Ok(dir) = app.path().app_data_dir() {
+            restore_from_backup_if_corrupt(&dir.join(STORE_PATH));
+        }
+    });
+}
+
 /// A helper function to reduce boilerplate when performing a read operation on the store.
 fn with_store_get<R: Runtime, F, T>(app: AppHandle<R>, operation: F) -> Result<T, String>
 where
@@ -98,13 +229,27 @@ where
 {
     // Attempt migration from legacy store if needed
     migrate_from_legacy_store(&app);
+    ensure_store_file_valid(&app);
     
-    let store = app
-        .store(PathBuf::from(STORE_PATH))
-        .map_err(|e| e.to_string())?;
+    let store = open_settings_store(&app)?;
     Ok(operation(&store))
 }
 
+/// Drops every value held by the loaded store instance.
+///
+/// Deleting the settings file alone is not enough: the store plugin keeps its
+/// values in memory and would write them back on the next save.
+pub(crate) fn clear_loaded_store<R: Runtime>(app: &AppHandle<R>) -> Result<(), String> {
+    clear_loaded_store_at(app, Path::new(STORE_PATH))
+}
+
+fn clear_loaded_store_at<R: Runtime>(app: &AppHandle<R>, path: &Path) -> Result<(), String> {
+    let store = open_store_at(app, path)?;
+    store.clear();
+    log::info!("Cleared in-memory settings store");
+    Ok(())
+}
+
 /// Returns the path to the Scoop configuration file.
 ///
 /// Scoop uses: `~/.config/scoop/config.json` where ~ is %USERPROFILE%
@@ -160,9 +305,88 @@ pub fn get_scoop_path<R: Runtime>(app: AppHandle<R>) -> Result<Option<String>, S
     })
 }
 
-/// Sets the Scoop path in the store.
+/// Switches Rscoop to another Scoop installation at runtime.
+///
+/// The path must contain `apps` and `buckets` directories. The choice is persisted
+/// (and picked up again by `resolve_scoop_root` on startup), the in-memory path is
+/// updated and the caches built from the previous installation are invalidated.
 #[tauri::command]
-pub fn set_scoop_path<R: Runtime>(app: AppHandle<R>, path: String) -> Result<(), String> {
+pub async fn set_scoop_path(
+    app: AppHandle,
+    state: tauri::State<'_, crate::state::AppState>,
+    path: String,
+) -> Result<(), String> {
+    let root = PathBuf::from(path.trim());
+    if !root.join("apps").is_dir() || !root.join("buckets").is_dir() {
+        return Err(format!(
+            "'{}' does not look like a Scoop installation (missing apps or buckets directory)",
+            root.display()
+        ));
+    }
+
+    log::info!("Switching Scoop path to {}", root.display());
+    persist_scoop_path(app.clone(), root.to_string_lossy().to_string())?;
+
+    crate::utils::clear_scoop_root_cache();
+    crate::commands::search::invalidate_manifest_cache().await;
+    crate::commands::installed::invalidate_installed_cache(state).await;
+    crate::commands::apps_watcher::restart_if_running(&app);
+    Ok(())
+}
+
+/// Writes several flat configuration values in a single store save.
+///
+/// Unlike `set_config_value` this does not wake the scheduler or refresh the tray, so it
+/// is meant for bookkeeping values written by background tasks.
+pub(crate) fn set_config_values<R: Runtime>(
+    app: AppHandle<R>,
+    values: Vec<(String, Value)>,
+) -> Result<(), String> {
+    with_store_mut(app, move |store| {
+        for (key, value) in values {
+            store.set(key, value);
+        }
+    })
+}
+
+/// Reads the list stored under `key`, oldest entry first. A missing or unreadable list
+/// reads as empty.
+pub(crate) fn read_config_list<R: Runtime, T: DeserializeOwned>(
+    app: &AppHandle<R>,
+    key: &str,
+) -> Vec<T> {
+    get_config_value(app.clone(), key.to_string())
+        .ok()
+        .flatten()
+        .and_then(|v| serde_json::from_value::<Vec<T>>(v).ok())
+        .unwrap_or_default()
+}
+
+/// Appends `entry` to the list stored under `key`, dropping the oldest entries so that
+/// at most `max_len` remain.
+pub(crate) fn append_config_list<R: Runtime, T: Serialize + DeserializeOwned>(
+    app: &AppHandle<R>,
+    key: &str,
+    entry: T,
+    max_len: usize,
+) -> Result<(), String> {
+    let mut entries: Vec<T> = read_config_list(app, key);
+    entries.push(entry);
+    if entries.len() > max_len {
+        let excess = entries.len() - max_len;
+        entries.drain(..excess);
+    }
+
+    let value = serde_json::to_value(&entries)
+        .map_err(|e| format!("Failed to serialize {}: {}", key, e))?;
+    set_config_values(app.clone(), vec![(key.to_string(), value)])
+}
+
+/// Stores the Scoop path in the settings and updates the in-memory app state.
+pub(crate) fn persist_scoop_path<R: Runtime>(
+    app: AppHandle<R>,
+    path: String,
+) -> Result<(), String> {
     let path_clone = path.clone();
     with_store_mut(app.clone(), move |store| {
         // Try to update in settings.scoopPath (new unified format)
@@ -238,6 +462,75 @@ pub fn detect_scoop_path() -> Result<String, String> {
     Err("Could not detect Scoop installation directory. Please set the path manually.".to_string())
 }
 
+/// A Scoop installation found by `detect_scoop_installations`.
+#[derive(serde::Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoopInstall {
+    pub path: String,
+    /// Where the path came from: "SCOOP", "SCOOP_GLOBAL" or "default".
+    pub source: String,
+    /// "user" or "global".
+    pub scope: String,
+    pub app_count: usize,
+    /// Whether this is the installation Rscoop currently manages.
+    pub is_active: bool,
+}
+
+/// Lists the Scoop installations referenced by `SCOOP`, `SCOOP_GLOBAL` and the default
+/// `%USERPROFILE%\scoop` location, with the number of installed apps in each.
+///
+/// The chosen installation can then be selected with `set_scoop_path`.
+#[tauri::command]
+pub fn detect_scoop_installations(
+    state: tauri::State<'_, crate::state::AppState>,
+) -> Result<Vec<ScoopInstall>, String> {
+    let mut candidates: Vec<(PathBuf, &str, &str)> = Vec::new();
+    if let Ok(path) = std::env::var("SCOOP") {
+        candidates.push((PathBuf::from(path), "SCOOP", "user"));
+    }
+    if let Ok(path) = std::env::var("SCOOP_GLOBAL") {
+        candidates.push((PathBuf::from(path), "SCOOP_GLOBAL", "global"));
+    }
+    if let Some(home) = dirs::home_dir() {
+        candidates.push((home.join("scoop"), "default", "user"));
+    }
+
+    let active = state.scoop_path();
+    let mut installs: Vec<ScoopInstall> = Vec::new();
+    for (path, source, scope) in candidates {
+        if !path.join("apps").is_dir() {
+            continue;
+        }
+        let path_str = path.to_string_lossy().to_string();
+        if installs
+            .iter()
+            .any(|install| install.path.eq_ignore_ascii_case(&path_str))
+        {
+            continue;
+        }
+
+        let app_count = fs::read_dir(path.join("apps"))
+            .map(|entries| {
+                entries
+                    .filter_map(Result::ok)
+                    .filter(|entry| entry.path().is_dir())
+                    .count()
+            })
+            .unwrap_or(0);
+
+        installs.push(ScoopInstall {
+            is_active: path == active,
+            path: path_str,
+            source: source.to_string(),
+            scope: scope.to_string(),
+            app_count,
+        });
+    }
+
+    log::info!("Detected {} Scoop installations", installs.len());
+    Ok(installs)
+}
+
 
 
 /// Gets a generic configuration value from the store by its key.
@@ -248,23 +541,26 @@ pub fn get_config_value<R: Runtime>(
     app: AppHandle<R>,
     key: String,
 ) -> Result<Option<Value>, String> {
-    with_store_get(app, |store| {
-        // First try direct access
-        if let Some(value) = store.get(&key) {
-            return Some(value.clone());
-        }
+    with_store_get(app, |store| lookup_config_value(store, &key))
+}
 
-        // If key contains dots, try to access from nested 'settings' object
-        if key.contains('.') {
-            if let Some(settings_value) = store.get("settings") {
-                if let Some(nested_value) = get_nested_value(&settings_value, &key) {
-                    return Some(nested_value.clone());
-                }
+/// Looks up `key` in the store, falling back to the nested `settings` object for dotted keys.
+fn lookup_config_value<R: Runtime>(store: &Store<R>, key: &str) -> Option<Value> {
+    // First try direct access
+    if let Some(value) = store.get(key) {
+        return Some(value.clone());
+    }
+
+    // If key contains dots, try to access from nested 'settings' object
+    if key.contains('.') {
+        if let Some(settings_value) = store.get("settings") {
+            if let Some(nested_value) = get_nested_value(&settings_value, key) {
+                return Some(nested_value.clone());
             }
         }
+    }
 
-        None
-    })
+    None
 }
 
 /// Helper function to get nested values using dot notation
@@ -281,6 +577,17 @@ fn get_nested_value<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
     Some(current)
 }
 
+/// Settings that affect when the background scheduler runs. The scheduler's own
+/// timestamp keys are deliberately absent so that it does not wake itself up.
+const SCHEDULER_SETTING_KEYS: &[&str] = &[
+    crate::scheduler::AUTO_UPDATE_INTERVAL_KEY,
+    crate::scheduler::MIN_GAP_SECS_KEY,
+    crate::scheduler::AUTO_UPDATE_PACKAGES_KEY,
+    crate::scheduler::SCHEDULER_PAUSED_KEY,
+    crate::scheduler::QUIET_START_KEY,
+    crate::scheduler::QUIET_END_KEY,
+];
+
 /// Sets a generic configuration value in the store.
 #[tauri::command]
 pub fn set_config_value(
@@ -291,6 +598,13 @@ pub fn set_config_value(
     let key_clone = key.clone();
     with_store_mut(app.clone(), move |store| store.set(key_clone, value))?;
 
+    // Let the scheduler pick up interval changes right away
+    if SCHEDULER_SETTING_KEYS.contains(&key.as_str()) {
+        if let Some(state) = app.try_state::<crate::state::AppState>() {
+            state.wake_scheduler();
+        }
+    }
+
     // Trigger tray refresh for relevant settings
     match key.as_str() {
         "settings.language" | "tray.appsList" | "settings.window.trayAppsEnabled" => {
@@ -307,6 +621,265 @@ pub fn set_config_value(
     Ok(())
 }
 
+/// Pauses or resumes the background scheduler without touching its interval.
+#[tauri::command]
+pub fn set_scheduler_paused(app: AppHandle<tauri::Wry>, paused: bool) -> Result<(), String> {
+    log::info!("Setting scheduler paused: {}", paused);
+    set_config_value(
+        app,
+        crate::scheduler::SCHEDULER_PAUSED_KEY.to_string(),
+        serde_json::json!(paused),
+    )
+}
+
+/// Clears the scheduler's run timestamps so the next auto-update runs right away.
+///
+/// Recovers from a schedule stuck because a timestamp was written with a wrong clock.
+#[tauri::command]
+pub fn reset_update_schedule(app: AppHandle<tauri::Wry>) -> Result<(), String> {
+    log::info!("Resetting auto-update schedule");
+    for key in [
+        crate::scheduler::LAST_UPDATE_TS_KEY,
+        crate::scheduler::LAST_RUN_TS_KEY,
+    ] {
+        set_config_value(app.clone(), key.to_string(), serde_json::json!(0))?;
+    }
+    if let Some(state) = app.try_state::<crate::state::AppState>() {
+        state.wake_scheduler();
+    }
+    Ok(())
+}
+
+/// Reads a boolean setting, treating a missing or non-boolean value as `false`.
+fn get_bool_setting<R: Runtime>(app: AppHandle<R>, key: &str) -> Result<bool, String> {
+    Ok(get_config_value(app, key.to_string())?
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false))
+}
+
+/// Returns whether packages are updated after each scheduled bucket refresh.
+#[tauri::command]
+pub fn get_auto_update_packages<R: Runtime>(app: AppHandle<R>) -> Result<bool, String> {
+    get_bool_setting(app, crate::scheduler::AUTO_UPDATE_PACKAGES_KEY)
+}
+
+/// Enables or disables updating packages after each scheduled bucket refresh.
+#[tauri::command]
+pub fn set_auto_update_packages(app: AppHandle<tauri::Wry>, enabled: bool) -> Result<(), String> {
+    log::info!("Setting auto-update packages: {}", enabled);
+    set_config_value(
+        app,
+        crate::scheduler::AUTO_UPDATE_PACKAGES_KEY.to_string(),
+        serde_json::json!(enabled),
+    )
+}
+
+/// Returns whether scheduled runs are silent, i.e. not streamed to the UI.
+#[tauri::command]
+pub fn get_silent_update_enabled<R: Runtime>(app: AppHandle<R>) -> Result<bool, String> {
+    get_bool_setting(app, crate::scheduler::SILENT_UPDATE_KEY)
+}
+
+/// Enables or disables silent scheduled runs.
+#[tauri::command]
+pub fn set_silent_update_enabled(app: AppHandle<tauri::Wry>, enabled: bool) -> Result<(), String> {
+    log::info!("Setting silent update: {}", enabled);
+    set_config_value(
+        app,
+        crate::scheduler::SILENT_UPDATE_KEY.to_string(),
+        serde_json::json!(enabled),
+    )
+}
+
+/// Returns the auto-update interval setting, `"off"` when unset.
+#[tauri::command]
+pub fn get_auto_update_interval<R: Runtime>(app: AppHandle<R>) -> Result<String, String> {
+    Ok(
+        get_config_value(app, crate::scheduler::AUTO_UPDATE_INTERVAL_KEY.to_string())?
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_else(|| "off".to_string()),
+    )
+}
+
+/// Sets the auto-update interval.
+///
+/// Accepts `"off"`, `"1h"`, `"6h"`, `"24h"`/`"1d"`, `"7d"`/`"1w"`, `"custom:<secs>"` or a
+/// plain number of seconds; anything else is rejected instead of silently disabling
+/// the scheduler.
+#[tauri::command]
+pub fn set_auto_update_interval(
+    app: AppHandle<tauri::Wry>,
+    interval: String,
+) -> Result<(), String> {
+    let interval = interval.trim().to_string();
+    if interval != "off" {
+        match crate::scheduler::parse_update_interval(&interval) {
+            Some(secs) if secs > 0 => {}
+            _ => return Err(format!("Invalid auto-update interval: '{}'", interval)),
+        }
+    }
+    log::info!("Setting auto-update interval: {}", interval);
+    set_config_value(
+        app,
+        crate::scheduler::AUTO_UPDATE_INTERVAL_KEY.to_string(),
+        serde_json::json!(interval),
+    )
+}
+
+/// Switches package operations between per-user and global (`--global`) apps.
+///
+/// Enabling fails if global operations cannot run, e.g. because Rscoop is not elevated.
+#[tauri::command]
+pub fn set_use_global(app: AppHandle<tauri::Wry>, enabled: bool) -> Result<(), String> {
+    if enabled {
+        crate::commands::scoop::ensure_global_supported()?;
+    }
+    log::info!("Setting global scoop operations: {}", enabled);
+    set_config_value(
+        app,
+        crate::commands::scoop::USE_GLOBAL_KEY.to_string(),
+        serde_json::json!(enabled),
+    )
+}
+
+/// Expected value type of a portable setting, used to validate imports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SettingKind {
+    Bool,
+    UInt,
+    Str,
+    StrList,
+}
+
+impl SettingKind {
+    fn accepts(self, value: &Value) -> bool {
+        match self {
+            SettingKind::Bool => value.is_boolean(),
+            SettingKind::UInt => value.is_u64(),
+            SettingKind::Str => value.is_string(),
+            SettingKind::StrList => value
+                .as_array()
+                .is_some_and(|items| items.iter().all(Value::is_string)),
+        }
+    }
+}
+
+/// Rscoop-specific settings included in a settings export. Timestamps, history and
+/// machine-specific paths are left out as they do not make sense on another machine.
+const PORTABLE_SETTINGS: &[(&str, SettingKind)] = &[
+    (crate::scheduler::AUTO_UPDATE_INTERVAL_KEY, SettingKind::Str),
+    (crate::scheduler::MIN_GAP_SECS_KEY, SettingKind::UInt),
+    (
+        crate::scheduler::AUTO_UPDATE_PACKAGES_KEY,
+        SettingKind::Bool,
+    ),
+    (crate::scheduler::SILENT_UPDATE_KEY, SettingKind::Bool),
+    (
+        crate::commands::update_log::UPDATE_LOG_ENABLED_KEY,
+        SettingKind::Bool,
+    ),
+    (
+        crate::commands::bucket_install::UPDATE_RETRIES_KEY,
+        SettingKind::UInt,
+    ),
+    (
+        crate::commands::doctor::cleanup::CLEANUP_EXCLUDE_KEY,
+        SettingKind::StrList,
+    ),
+    (
+        crate::commands::auto_cleanup::AUTO_CLEANUP_DISABLED_KEY,
+        SettingKind::Bool,
+    ),
+    (crate::commands::hold::HOLD_LIST_KEY, SettingKind::StrList),
+    (
+        crate::commands::debug::LOG_RETENTION_MODE_KEY,
+        SettingKind::Str,
+    ),
+    (
+        crate::commands::debug::LOG_RETENTION_VALUE_KEY,
+        SettingKind::UInt,
+    ),
+    (
+        crate::commands::powershell::OPERATION_TIMEOUT_KEY,
+        SettingKind::UInt,
+    ),
+    (
+        crate::commands::powershell::BATCH_OUTPUT_KEY,
+        SettingKind::Bool,
+    ),
+    (
+        crate::commands::uninstall::MAX_CONCURRENT_KEY,
+        SettingKind::UInt,
+    ),
+    (
+        crate::commands::install::VERIFY_AFTER_INSTALL_KEY,
+        SettingKind::Bool,
+    ),
+    (crate::commands::scoop::USE_GLOBAL_KEY, SettingKind::Bool),
+    (crate::scheduler::SCHEDULER_PAUSED_KEY, SettingKind::Bool),
+    (crate::scheduler::QUIET_START_KEY, SettingKind::Str),
+    (crate::scheduler::QUIET_END_KEY, SettingKind::Str),
+    (crate::scheduler::SKIP_WHEN_OFFLINE_KEY, SettingKind::Bool),
+    (
+        crate::scheduler::NOTIFY_ON_SILENT_COMPLETE_KEY,
+        SettingKind::Bool,
+    ),
+    (crate::scheduler::NOTIFY_DND_START_KEY, SettingKind::Str),
+    (crate::scheduler::NOTIFY_DND_END_KEY, SettingKind::Str),
+];
+
+/// Exports the rscoop-specific settings to a JSON file at `path`.
+///
+/// Only settings that have been set are written. Returns the number of exported settings.
+#[tauri::command]
+pub fn export_settings<R: Runtime>(app: AppHandle<R>, path: String) -> Result<usize, String> {
+    let mut settings = Map::new();
+    for (key, _) in PORTABLE_SETTINGS {
+        if let Some(value) = get_config_value(app.clone(), key.to_string())? {
+            settings.insert(key.to_string(), value);
+        }
+    }
+
+    let content = serde_json::to_string_pretty(&Value::Object(settings.clone()))
+        .map_err(|e| format!("Failed to serialize settings: {}", e))?;
+    fs::write(&path, content).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+
+    log::info!("Exported {} settings to {}", settings.len(), path);
+    Ok(settings.len())
+}
+
+/// Imports settings previously written by `export_settings` from `path`.
+///
+/// Unknown keys and values of the wrong type are skipped with a warning.
+/// Returns the number of imported settings.
+#[tauri::command]
+pub fn import_settings(app: AppHandle<tauri::Wry>, path: String) -> Result<usize, String> {
+    let content =
+        fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let settings = match serde_json::from_str::<Value>(&content) {
+        Ok(Value::Object(settings)) => settings,
+        Ok(_) => return Err(format!("{} does not contain a settings object", path)),
+        Err(e) => return Err(format!("Failed to parse {}: {}", path, e)),
+    };
+
+    let mut imported = 0;
+    for (key, value) in settings {
+        let Some((_, kind)) = PORTABLE_SETTINGS.iter().find(|(k, _)| *k == key) else {
+            log::warn!("Skipping unknown setting '{}' during import", key);
+            continue;
+        };
+        if !kind.accepts(&value) {
+            log::warn!("Skipping setting '{}' with invalid value {}", key, value);
+            continue;
+        }
+        set_config_value(app.clone(), key, value)?;
+        imported += 1;
+    }
+
+    log::info!("Imported {} settings from {}", imported, path);
+    Ok(imported)
+}
+
 /// Gets the Scoop configuration as a JSON object
 #[tauri::command]
 pub fn get_scoop_config() -> Result<Option<serde_json::Map<String, serde_json::Value>>, String> {
@@ -410,6 +983,43 @@ pub async fn run_scoop_command(window: tauri::Window, command: String) -> Result
         crate::commands::powershell::EVENT_FINISHED,
         crate::commands::powershell::EVENT_CANCEL,
         None,
+        None,
+    )
+    .await
+}
+
+/// Sets a Scoop config value by running `scoop config <key> <value>` and streaming its output.
+///
+/// An empty value removes the key with `scoop config rm <key>`.
+#[tauri::command]
+pub async fn set_scoop_config(
+    window: tauri::Window,
+    key: String,
+    value: String,
+) -> Result<(), String> {
+    if key.is_empty()
+        || !key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        return Err(format!("Invalid Scoop config key: '{}'", key));
+    }
+
+    // Values may be credentials (e.g. `gh_token`), so only the key is ever logged
+    let (command, display) = if value.is_empty() {
+        let command = format!("scoop config rm {}", key);
+        (command.clone(), command)
+    } else {
+        (
+            format!("scoop config {} '{}'", key, value.replace('\'', "''")),
+            format!("scoop config {} <redacted>", key),
+        )
+    };
+    crate::commands::powershell::run_and_stream_redacted_command(
+        window,
+        command,
+        &display,
+        format!("Set Scoop config {}", key),
     )
     .await
 }
@@ -435,6 +1045,7 @@ pub async fn run_powershell_command(window: tauri::Window, command: String) -> R
         crate::commands::powershell::EVENT_FINISHED,
         crate::commands::powershell::EVENT_CANCEL,
         None,
+        None,
     )
     .await
 }
@@ -453,4 +1064,42 @@ mod tests {
             assert!(true);
         }
     }
+
+    #[test]
+    fn test_truncated_settings_file_is_restored_from_backup() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(STORE_PATH);
+
+        write_atomic_with_backup(&path, br#"{"settings":{"theme":"dark"}}"#).unwrap();
+        write_atomic_with_backup(&path, br#"{"settings":{"theme":"light"}}"#).unwrap();
+        assert!(backup_path(&path).exists());
+
+        // Simulate a crash that left a half-written file behind
+        fs::write(&path, br#"{"settings":{"th"#).unwrap();
+        assert!(restore_from_backup_if_corrupt(&path));
+
+        let restored: Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(restored["settings"]["theme"], "dark");
+        assert!(!restore_from_backup_if_corrupt(&path));
+    }
+
+    #[test]
+    fn test_setting_reads_default_after_clearing_loaded_store() {
+        let app = tauri::test::mock_builder()
+            .plugin(tauri_plugin_store::Builder::new().build())
+            .build(tauri::test::mock_context(tauri::test::noop_assets()))
+            .unwrap();
+        let handle = app.handle().clone();
+        // An absolute path keeps the store out of the real app data directory
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(STORE_PATH);
+
+        let store = open_store_at(&handle, &path).unwrap();
+        store.set("settings", serde_json::json!({ "ui": { "theme": "dark" } }));
+        assert!(lookup_config_value(&store, "ui.theme").is_some());
+
+        clear_loaded_store_at(&handle, &path).unwrap();
+        let store = open_store_at(&handle, &path).unwrap();
+        assert_eq!(lookup_config_value(&store, "ui.theme"), None);
+    }
 }
\ No newline at end of file
@@ -2,7 +2,10 @@
 //! This implements the equivalent of `scoop status` command.
 
 use crate::commands::installed::get_installed_packages_full;
-use crate::models::{AppStatusInfo, ScoopPackage as InstalledPackage, ScoopStatus};
+use crate::commands::powershell;
+use crate::models::{
+    AppStatusInfo, ScoopInstallationStatus, ScoopPackage as InstalledPackage, ScoopStatus,
+};
 use crate::state::AppState;
 use crate::utils::locate_package_manifest;
 use git2::Repository;
@@ -288,3 +291,147 @@ pub async fn check_scoop_status<R: Runtime>(
         is_everything_ok,
     })
 }
+
+/// Extracts the version line from the output of `scoop --version`.
+fn parse_scoop_version(output: &str) -> Option<String> {
+    let mut lines = output.lines().map(str::trim).filter(|l| !l.is_empty());
+    let first = lines.next()?;
+    if first.starts_with("Current Scoop version") {
+        lines.next().map(String::from)
+    } else {
+        Some(first.to_string())
+    }
+}
+
+/// Checks whether the scoop `shims` directory is listed in the PATH environment variable.
+fn shims_dir_on_path(shims_dir: &Path) -> bool {
+    let path_var = match std::env::var_os("PATH") {
+        Some(path_var) => path_var,
+        None => return false,
+    };
+    let target = shims_dir.to_string_lossy().trim_end_matches('\\').to_lowercase();
+    std::env::split_paths(&path_var).any(|p| {
+        p.to_string_lossy().trim_end_matches('\\').to_lowercase() == target
+    })
+}
+
+/// Returns the drive type and free space of the volume that contains `path`.
+///
+/// A scoop directory on a network share or removable drive explains slow installs
+/// and cleanups, so the UI can warn about it.
+#[cfg(windows)]
+pub(crate) fn volume_info(path: &Path) -> (Option<String>, Option<u64>) {
+    use std::os::windows::prelude::OsStrExt;
+    use windows_sys::Win32::{
+        Foundation::MAX_PATH,
+        Storage::FileSystem::{GetDiskFreeSpaceExW, GetDriveTypeW, GetVolumePathNameW},
+    };
+
+    let path_ws: Vec<u16> = path.as_os_str().encode_wide().chain(Some(0)).collect();
+    let mut volume_path_buf = vec![0u16; MAX_PATH as usize];
+
+    let result = unsafe {
+        GetVolumePathNameW(
+            path_ws.as_ptr(),
+            volume_path_buf.as_mut_ptr(),
+            volume_path_buf.len() as u32,
+        )
+    };
+    if result == 0 {
+        log::warn!(
+            "GetVolumePathNameW failed for {}: {}",
+            path.display(),
+            std::io::Error::last_os_error()
+        );
+        return (None, None);
+    }
+
+    let drive_type = match unsafe { GetDriveTypeW(volume_path_buf.as_ptr()) } {
+        2 => "removable",
+        3 => "fixed",
+        4 => "network",
+        5 => "cdrom",
+        6 => "ram",
+        _ => "unknown",
+    };
+
+    let mut free_bytes: u64 = 0;
+    let result = unsafe {
+        GetDiskFreeSpaceExW(
+            volume_path_buf.as_ptr(),
+            &mut free_bytes,
+            std::ptr::null_mut(),
+            std::ptr::null_mut(),
+        )
+    };
+    let free_space = if result != 0 { Some(free_bytes) } else { None };
+
+    (Some(drive_type.to_string()), free_space)
+}
+
+#[cfg(not(windows))]
+pub(crate) fn volume_info(_path: &Path) -> (Option<String>, Option<u64>) {
+    (None, None)
+}
+
+/// Verifies that Scoop is installed at the configured path and reachable on PATH.
+#[tauri::command]
+pub async fn check_scoop_installation(
+    state: State<'_, AppState>,
+) -> Result<ScoopInstallationStatus, String> {
+    let scoop_path = state.scoop_path();
+    log::info!("Checking scoop installation at {}", scoop_path.display());
+
+    let shims_dir = scoop_path.join("shims");
+    let shim_exists = ["scoop.ps1", "scoop.cmd", "scoop"]
+        .iter()
+        .any(|name| shims_dir.join(name).is_file());
+    let shims_on_path = shims_dir_on_path(&shims_dir);
+    let apps_dir_exists = scoop_path.join("apps").is_dir();
+    let buckets_dir_exists = scoop_path.join("buckets").is_dir();
+
+    let version = if shim_exists {
+        match powershell::create_powershell_command("scoop --version").output().await {
+            Ok(output) if output.status.success() => {
+                parse_scoop_version(&String::from_utf8_lossy(&output.stdout))
+            }
+            Ok(output) => {
+                log::warn!(
+                    "scoop --version exited with {}: {}",
+                    output.status,
+                    String::from_utf8_lossy(&output.stderr).trim()
+                );
+                None
+            }
+            Err(e) => {
+                log::warn!("Failed to run scoop --version: {}", e);
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    let is_installed = shim_exists && apps_dir_exists && version.is_some();
+
+    let (drive_type, free_space_bytes) = volume_info(&scoop_path);
+    if matches!(drive_type.as_deref(), Some("network") | Some("removable")) {
+        log::warn!(
+            "Scoop directory {} is on a {} drive; operations may be slow",
+            scoop_path.display(),
+            drive_type.as_deref().unwrap_or_default()
+        );
+    }
+
+    Ok(ScoopInstallationStatus {
+        scoop_path: scoop_path.display().to_string(),
+        shim_exists,
+        shims_on_path,
+        version,
+        apps_dir_exists,
+        buckets_dir_exists,
+        is_installed,
+        drive_type,
+        free_space_bytes,
+    })
+}
@@ -1,10 +1,15 @@
 //! Commands for uninstalling packages and clearing the cache.
 use crate::commands::auto_cleanup::trigger_auto_cleanup;
 use crate::commands::installed::invalidate_installed_cache;
+use crate::commands::operation_history;
+use crate::commands::powershell::{new_operation_id, CommandResult, EVENT_FINISHED};
 use crate::commands::scoop::{self, ScoopOp};
 use crate::commands::search::invalidate_manifest_cache;
-use crate::state::AppState;
-use tauri::{AppHandle, State, Window};
+use crate::error::AppError;
+use crate::state::{ActiveOperation, AppState};
+use serde::Serialize;
+use std::time::Instant;
+use tauri::{AppHandle, Emitter, Manager, State, Window};
 
 /// Uninstalls a Scoop package.
 ///
@@ -15,6 +20,7 @@ use tauri::{AppHandle, State, Window};
 /// * `window` - The Tauri window to emit events to.
 /// * `package_name` - The name of package to uninstall.
 /// * `bucket` - The bucket package belongs to (for logging purposes).
+/// * `operation_id` - The id the output is streamed under; generated when omitted.
 #[tauri::command]
 pub async fn uninstall_package(
     window: Window,
@@ -22,14 +28,17 @@ pub async fn uninstall_package(
     state: State<'_, AppState>,
     package_name: String,
     bucket: String,
-) -> Result<(), String> {
-    execute_package_operation(
+    operation_id: Option<String>,
+) -> Result<(), AppError> {
+    execute_package_operation_with_id(
         window.clone(),
         ScoopOp::Uninstall,
         &package_name,
         Some(&bucket),
+        operation_id,
     )
-    .await?;
+    .await
+    .map_err(AppError::OperationFailed)?;
     invalidate_manifest_cache().await;
     invalidate_installed_cache(state.clone()).await;
 
@@ -39,6 +48,181 @@ pub async fn uninstall_package(
     Ok(())
 }
 
+/// Reinstalls the package removed by the last operation, reusing its cached download.
+///
+/// Fails if the last recorded operation was not a successful uninstall, or if the
+/// download cache no longer holds a version of the package. When the bucket's current
+/// version is cached it is installed normally, otherwise the newest cached version is
+/// installed as `name@version`.
+#[tauri::command]
+pub async fn undo_last_uninstall(
+    window: Window,
+    app: AppHandle,
+    state: State<'_, AppState>,
+) -> Result<(), AppError> {
+    let last = operation_history::get_last_operation(app.clone())
+        .ok_or_else(|| AppError::InvalidInput("No operation has been recorded".to_string()))?;
+    if last.op_type != "uninstall" || !last.success {
+        return Err(AppError::InvalidInput(format!(
+            "The last operation was not a successful uninstall ({} {})",
+            last.op_type, last.target
+        )));
+    }
+
+    let name = last.target;
+    let scoop_path = state.scoop_path();
+    let cached_versions = cached_versions(&scoop_path.join("cache"), &name);
+    let manifest_version = crate::utils::locate_package_manifest(&scoop_path, &name, None)
+        .ok()
+        .and_then(|(path, _)| std::fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+        .and_then(|manifest| manifest.get("version")?.as_str().map(str::to_string));
+
+    let target = match manifest_version {
+        Some(version) if cached_versions.contains(&version) => name.clone(),
+        _ => match cached_versions.first() {
+            Some(version) => format!("{}@{}", name, version),
+            None => {
+                return Err(AppError::OperationFailed(format!(
+                    "Cannot undo uninstall of '{}': its download is no longer in the cache",
+                    name
+                )))
+            }
+        },
+    };
+
+    log::info!("Undoing uninstall of '{}' by installing '{}'", name, target);
+    execute_package_operation(window, ScoopOp::Install, &target, None)
+        .await
+        .map_err(AppError::OperationFailed)?;
+    invalidate_manifest_cache().await;
+    invalidate_installed_cache(state).await;
+    Ok(())
+}
+
+/// Lists the versions of `name` in the download cache, newest first.
+///
+/// Cache files are named `name#version#hash.ext`.
+fn cached_versions(cache_dir: &std::path::Path, name: &str) -> Vec<String> {
+    let mut versions: Vec<String> = std::fs::read_dir(cache_dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .filter_map(|entry| {
+                    let file_name = entry.file_name().to_string_lossy().to_string();
+                    let mut parts = file_name.split('#');
+                    let cached_name = parts.next()?;
+                    let version = parts.next()?;
+                    cached_name
+                        .eq_ignore_ascii_case(name)
+                        .then(|| version.to_string())
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    versions.sort_by(|a, b| crate::commands::auto_cleanup::compare_versions(b, a));
+    versions.dedup();
+    versions
+}
+
+/// Outcome of one package in a batch operation.
+#[derive(Serialize, Debug, Clone)]
+pub struct BatchPackageResult {
+    pub name: String,
+    pub success: bool,
+    pub error: Option<String>,
+}
+
+/// Settings key limiting how many Scoop operations a batch command runs at once.
+///
+/// Defaults to 1. Cache clears only touch their own files and parallelize safely;
+/// installs, updates and uninstalls share Scoop's bucket git repositories and shim
+/// directory, so running them concurrently can collide on git locks.
+pub const MAX_CONCURRENT_KEY: &str = "operations.maxConcurrent";
+
+/// Reads `operations.maxConcurrent`, never returning less than 1.
+pub(crate) fn max_concurrent_operations(app: &AppHandle) -> usize {
+    crate::commands::settings::get_config_value(app.clone(), MAX_CONCURRENT_KEY.to_string())
+        .ok()
+        .flatten()
+        .and_then(|v| v.as_u64())
+        .map_or(1, |n| n.max(1) as usize)
+}
+
+/// Runs `op` for every package, with at most `max_concurrent` operations in flight.
+///
+/// Results are returned in the order of `names`.
+pub(crate) async fn run_batch_operation(
+    window: &Window,
+    op: ScoopOp,
+    names: Vec<String>,
+    max_concurrent: usize,
+) -> Vec<BatchPackageResult> {
+    let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(max_concurrent.max(1)));
+    let mut tasks = tokio::task::JoinSet::new();
+
+    for (index, name) in names.into_iter().enumerate() {
+        let window = window.clone();
+        let semaphore = semaphore.clone();
+        tasks.spawn(async move {
+            let _permit = semaphore.acquire_owned().await;
+            let result = execute_package_operation(window, op, &name, None).await;
+            if let Err(e) = &result {
+                log::warn!("Batch {:?} failed for '{}': {}", op, name, e);
+            }
+            (
+                index,
+                BatchPackageResult {
+                    name,
+                    success: result.is_ok(),
+                    error: result.err(),
+                },
+            )
+        });
+    }
+
+    let mut results = Vec::new();
+    while let Some(joined) = tasks.join_next().await {
+        match joined {
+            Ok(result) => results.push(result),
+            Err(e) => log::error!("Batch operation task failed: {}", e),
+        }
+    }
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Uninstalls several Scoop packages, `operations.maxConcurrent` at a time (one by default).
+///
+/// Output of every uninstall is streamed through the usual operation events. The
+/// installed and manifest caches are invalidated and auto cleanup is triggered once,
+/// after all packages have been processed.
+#[tauri::command]
+pub async fn uninstall_packages(
+    window: Window,
+    app: AppHandle,
+    state: State<'_, AppState>,
+    names: Vec<String>,
+) -> Result<Vec<BatchPackageResult>, AppError> {
+    let max_concurrent = max_concurrent_operations(&app);
+    log::info!(
+        "Uninstalling {} packages ({} at a time): {}",
+        names.len(),
+        max_concurrent,
+        names.join(", ")
+    );
+
+    let results = run_batch_operation(&window, ScoopOp::Uninstall, names, max_concurrent).await;
+
+    invalidate_manifest_cache().await;
+    invalidate_installed_cache(state.clone()).await;
+    trigger_auto_cleanup(app, state).await;
+
+    let succeeded = results.iter().filter(|r| r.success).count();
+    log::info!("Batch uninstall finished: {}/{} succeeded", succeeded, results.len());
+    Ok(results)
+}
+
 /// Clears the cache for a Scoop package.
 ///
 /// Note: The `bucket` parameter is not used by the underlying `scoop cache rm` command
@@ -48,6 +232,7 @@ pub async fn uninstall_package(
 /// * `window` - The Tauri window to emit events to.
 /// * `package_name` - The name of the package to clear the cache for.
 /// * `bucket` - The bucket the package belongs to (for logging purposes).
+/// * `version` - When set, only the cached downloads of this version are removed.
 #[tauri::command]
 pub async fn clear_package_cache(
     window: Window,
@@ -55,14 +240,20 @@ pub async fn clear_package_cache(
     state: State<'_, AppState>,
     package_name: String,
     bucket: String,
-) -> Result<(), String> {
-    execute_package_operation(
-        window,
-        ScoopOp::ClearCache,
-        &package_name,
-        Some(&bucket),
-    )
-    .await?;
+    version: Option<String>,
+) -> Result<(), AppError> {
+    let target = match version {
+        Some(version) => {
+            scoop::validate_version(&version).map_err(AppError::InvalidInput)?;
+            format!("{}@{}", package_name, version)
+        }
+        None => package_name,
+    };
+
+    execute_package_operation(window, ScoopOp::ClearCache, &target, Some(&bucket))
+        .await
+        .map_err(AppError::OperationFailed)?;
+    crate::commands::doctor::disk_usage::invalidate_disk_usage(&state).await;
 
     // Trigger auto cleanup after clearing cache
     trigger_auto_cleanup(app, state).await;
@@ -70,15 +261,61 @@ pub async fn clear_package_cache(
     Ok(())
 }
 
+/// Finished event for scoop runs whose outcome is only reported after a follow-up
+/// check. The frontend does not listen to it.
+const EVENT_FINISHED_PENDING_CHECK: &str = "operation-finished-pending-check";
+
+/// Check run after a successful scoop operation. Returns the success message, or the
+/// error that makes the operation fail.
+pub(crate) type PostOperationCheck = Box<dyn FnOnce() -> Result<String, String> + Send>;
+
 /// A helper function to execute a Scoop operation on a package.
 ///
 /// This function handles the common logic for parsing the bucket, logging the operation,
-/// and calling the underlying `execute_scoop` function.
-async fn execute_package_operation(
+/// and calling the underlying `execute_scoop` function. Operations act on global apps
+/// when `scoop.useGlobal` is enabled.
+pub(crate) async fn execute_package_operation(
+    window: Window,
+    op: ScoopOp,
+    package: &str,
+    bucket: Option<&str>,
+) -> Result<(), String> {
+    run_package_operation(window, op, package, bucket, None, None).await
+}
+
+/// Like `execute_package_operation`, but streams under `operation_id` when the caller
+/// (usually the frontend, which listens for it) provides one.
+pub(crate) async fn execute_package_operation_with_id(
     window: Window,
     op: ScoopOp,
     package: &str,
     bucket: Option<&str>,
+    operation_id: Option<String>,
+) -> Result<(), String> {
+    run_package_operation(window, op, package, bucket, operation_id, None).await
+}
+
+/// Like `execute_package_operation`, but `check` runs once scoop succeeded and decides
+/// the outcome. `operation-finished` is emitted only after the check, so the UI never
+/// shows success for an operation that then fails.
+pub(crate) async fn execute_package_operation_checked(
+    window: Window,
+    op: ScoopOp,
+    package: &str,
+    bucket: Option<&str>,
+    operation_id: Option<String>,
+    check: PostOperationCheck,
+) -> Result<(), String> {
+    run_package_operation(window, op, package, bucket, operation_id, Some(check)).await
+}
+
+async fn run_package_operation(
+    window: Window,
+    op: ScoopOp,
+    package: &str,
+    bucket: Option<&str>,
+    operation_id: Option<String>,
+    check: Option<PostOperationCheck>,
 ) -> Result<(), String> {
     log::info!(
         "Executing {} for package '{}' from bucket '{}'",
@@ -89,20 +326,81 @@ async fn execute_package_operation(
             ScoopOp::UpdateForce => "force updating",
             ScoopOp::ClearCache => "clearing cache for",
             ScoopOp::UpdateAll => "updating all",
+            ScoopOp::Reset => "resetting",
+            ScoopOp::Hold => "holding",
+            ScoopOp::Unhold => "unholding",
+            ScoopOp::BucketAdd => "adding bucket",
+            ScoopOp::BucketRemove => "removing bucket",
+            ScoopOp::Checkup => "running checkup for",
         },
         package,
         bucket.unwrap_or("default")
     );
 
-    let operation_id = Some(format!("{}-{}-{}", match op {
+    let op_type = match op {
         ScoopOp::Install => "install",
         ScoopOp::Uninstall => "uninstall",
         ScoopOp::Update => "update",
         ScoopOp::UpdateForce => "force-update",
         ScoopOp::ClearCache => "clear-cache",
         ScoopOp::UpdateAll => "update-all",
-    }, package, std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs()));
+        ScoopOp::Reset => "reset",
+        ScoopOp::Hold => "hold",
+        ScoopOp::Unhold => "unhold",
+        ScoopOp::BucketAdd => "bucket-add",
+        ScoopOp::BucketRemove => "bucket-remove",
+        ScoopOp::Checkup => "checkup",
+    };
+    let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap();
+    let operation_id =
+        operation_id.unwrap_or_else(|| new_operation_id(&format!("{}-{}", op_type, package)));
+
+    let app = window.app_handle().clone();
+    let global = scoop::use_global(&app, op)?;
+    let started = Instant::now();
 
+    let state = app.state::<AppState>();
+    state.begin_operation(ActiveOperation {
+        operation_id: operation_id.clone(),
+        op_type: op_type.to_string(),
+        target: package.to_string(),
+        started_at: now.as_millis() as u64,
+    });
+
+    let finished_event = if check.is_some() {
+        EVENT_FINISHED_PENDING_CHECK
+    } else {
+        EVENT_FINISHED
+    };
     // Pass the bucket option along; `execute_scoop` will handle whether it's used.
-    scoop::execute_scoop(window, op, Some(package), bucket, operation_id).await
+    let mut result = scoop::execute_scoop_with_finished_event(
+        window.clone(),
+        op,
+        Some(package),
+        bucket,
+        Some(operation_id.clone()),
+        global,
+        finished_event,
+    )
+    .await;
+
+    if let Some(check) = check {
+        let outcome = result.and_then(|()| check());
+        let (success, message) = match &outcome {
+            Ok(message) => (true, message.clone()),
+            Err(e) => (false, e.clone()),
+        };
+        let finished = CommandResult {
+            success,
+            message,
+            operation_id: Some(operation_id.clone()),
+        };
+        if let Err(e) = window.emit(EVENT_FINISHED, finished) {
+            log::error!("Failed to emit finished event: {}", e);
+        }
+        result = outcome.map(|_| ());
+    }
+    state.end_operation(&operation_id);
+    operation_history::record_operation(&app, op_type, package, started, &result);
+    result
 }
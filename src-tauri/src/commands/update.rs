@@ -1,9 +1,16 @@
 use crate::commands::auto_cleanup::trigger_auto_cleanup;
-use crate::commands::scoop::{self, ScoopOp};
+use crate::commands::info::get_installed_version;
+use crate::commands::installed::invalidate_installed_cache;
+use crate::commands::powershell::new_operation_id;
+use crate::commands::scoop::{self, output, ScoopOp};
+use crate::commands::updates::check_for_updates;
 use crate::state::AppState;
-use tauri::{AppHandle, State, Window};
+use serde::Serialize;
+use tauri::{AppHandle, Manager, State, Window};
 
 /// Updates a specific Scoop package.
+///
+/// Output is streamed under `operation_id`, or a generated id when it is omitted.
 #[tauri::command]
 pub async fn update_package(
     window: Window,
@@ -11,6 +18,7 @@ pub async fn update_package(
     state: State<'_, AppState>,
     package_name: String,
     force: Option<bool>,
+    operation_id: Option<String>,
 ) -> Result<(), String> {
     log::info!("Updating package '{}'", package_name);
     let op = if force.unwrap_or(false) {
@@ -20,9 +28,11 @@ pub async fn update_package(
         ScoopOp::Update
     };
     
-    let operation_id = Some(format!("update-{}-{}", package_name, std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs()));
+    let operation_id =
+        operation_id.or_else(|| Some(new_operation_id(&format!("update-{}", package_name))));
     
-    scoop::execute_scoop(window, op, Some(&package_name), None, operation_id).await?;
+    let global = scoop::use_global(&app, op)?;
+    scoop::execute_scoop(window, op, Some(&package_name), None, operation_id, global).await?;
 
     // Trigger auto cleanup after update
     trigger_auto_cleanup(app, state).await;
@@ -31,18 +41,22 @@ pub async fn update_package(
 }
 
 /// Updates all Scoop packages.
+///
+/// Output is streamed under `operation_id`, or a generated id when it is omitted.
 #[tauri::command]
 pub async fn update_all_packages(
     window: Window,
     app: AppHandle,
     state: State<'_, AppState>,
+    operation_id: Option<String>,
 ) -> Result<(), String> {
     log::info!("Updating all packages (manual)");
     
-    let operation_id = Some(format!("update-all-{}", std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_secs()));
+    let operation_id = operation_id.or_else(|| Some(new_operation_id("update-all")));
     
     // Execute the update through window streaming
-    let result = scoop::execute_scoop(window.clone(), ScoopOp::UpdateAll, None, None, operation_id).await;
+    let global = scoop::use_global(&app, ScoopOp::UpdateAll)?;
+    let result = scoop::execute_scoop(window.clone(), ScoopOp::UpdateAll, None, None, operation_id, global).await;
 
     // Return the original result (success or error)
     result?;
@@ -53,110 +67,242 @@ pub async fn update_all_packages(
     Ok(())
 }
 
-/// Headless variant used by background scheduler (no UI streaming). Returns update details.
+/// Runs the scheduled bucket (and optional package) update immediately, without
+/// waiting for the auto-update interval.
+///
+/// Fails if a bucket update is already running.
+#[tauri::command]
+pub async fn run_scheduled_update_now(
+    app: AppHandle,
+) -> Result<crate::scheduler::CycleReport, String> {
+    log::info!("Running scheduled update now (manual trigger)");
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    crate::scheduler::run_guarded_update_cycle(&app, now).await
+}
+
+/// Rolls a package back to a previously installed version using `scoop reset <name>@<version>`.
+///
+/// The target version must still exist under `apps/<name>/`.
+#[tauri::command]
+pub async fn rollback_package(
+    window: Window,
+    state: State<'_, AppState>,
+    package_name: String,
+    version: String,
+) -> Result<(), String> {
+    scoop::validate_version(&version)?;
+
+    let version_dir = scoop::apps_root(window.app_handle(), &state)
+        .join("apps")
+        .join(&package_name)
+        .join(&version);
+    if !version_dir.is_dir() {
+        return Err(format!(
+            "Version {} of '{}' is not installed (expected {})",
+            version,
+            package_name,
+            version_dir.display()
+        ));
+    }
+
+    log::info!("Rolling back '{}' to version {}", package_name, version);
+    let target = format!("{}@{}", package_name, version);
+    let operation_id = Some(new_operation_id(&format!("reset-{}", package_name)));
+
+    let global = scoop::use_global(window.app_handle(), ScoopOp::Reset)?;
+    scoop::execute_scoop(window, ScoopOp::Reset, Some(&target), None, operation_id, global).await?;
+    invalidate_installed_cache(state).await;
+
+    Ok(())
+}
+
+/// Outcome of a single package in a headless batch update.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PackageUpdateStatus {
+    Updated,
+    Failed,
+}
+
+/// Per-package result of a headless batch update.
+#[derive(Serialize, Debug, Clone)]
+pub struct PackageUpdateResult {
+    pub name: String,
+    pub old_version: String,
+    pub new_version: Option<String>,
+    pub status: PackageUpdateStatus,
+}
+
+impl PackageUpdateResult {
+    /// Formats the result as a single human-readable log line.
+    pub fn to_line(&self) -> String {
+        match self.status {
+            PackageUpdateStatus::Updated => format!(
+                "✓ Updated {}: {} → {}",
+                self.name,
+                self.old_version,
+                self.new_version.as_deref().unwrap_or("?")
+            ),
+            PackageUpdateStatus::Failed => format!(
+                "✗ Failed to update {} (still at {})",
+                self.name, self.old_version
+            ),
+        }
+    }
+}
+
+/// Structured result of `update_all_packages_headless`.
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct HeadlessUpdateReport {
+    pub results: Vec<PackageUpdateResult>,
+    /// First lines of scoop's output when it exited with an error.
+    pub error: Option<String>,
+}
+
+impl HeadlessUpdateReport {
+    pub fn updated_count(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|r| r.status == PackageUpdateStatus::Updated)
+            .count()
+    }
+
+    pub fn failed_count(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|r| r.status == PackageUpdateStatus::Failed)
+            .count()
+    }
+
+    /// Formatted lines suitable for the UI operation log.
+    pub fn formatted_lines(&self) -> Vec<String> {
+        let mut lines: Vec<String> = if self.results.is_empty() && self.error.is_none() {
+            vec!["All packages are up to date.".to_string()]
+        } else {
+            self.results.iter().map(PackageUpdateResult::to_line).collect()
+        };
+        if let Some(error) = &self.error {
+            lines.push(format!("Error: {}", error));
+        }
+        lines
+    }
+}
+
+/// Whether one of scoop's (lowercased) "up to date" lines names `package`.
+fn reported_up_to_date(up_to_date_lines: &[String], package: &str) -> bool {
+    let package = package.to_lowercase();
+    let quoted = format!("'{}'", package);
+    let prefixed = format!("{}:", package);
+    up_to_date_lines
+        .iter()
+        .any(|line| line.contains(&quoted) || line.starts_with(&prefixed))
+}
+
+/// Headless variant used by background scheduler (no UI streaming).
+///
+/// Instead of parsing scoop's (localized and changing) console output, the set of
+/// outdated packages is computed before the update and their installed versions are
+/// re-read from disk afterwards to determine what actually changed.
 pub async fn update_all_packages_headless(
     app: AppHandle,
     state: State<'_, AppState>,
-) -> Result<Vec<String>, String> {
+) -> Result<HeadlessUpdateReport, String> {
     use crate::commands::powershell;
-    use tokio::io::AsyncReadExt;
 
     log::info!("(Headless) Updating all packages");
-    let mut cmd = powershell::create_powershell_command("scoop update *");
-    let mut child = cmd
-        .spawn()
-        .map_err(|e| format!("Failed to spawn scoop update *: {}", e))?;
 
-    let mut stdout = String::new();
-    let mut stderr = String::new();
-    
-    // Capture stdout
-    if let Some(mut out) = child.stdout.take() {
-        let mut buf = [0u8; 8192];
-        // Read a chunk to avoid huge memory usage; not streaming to UI
-        if let Ok(n) = out.read(&mut buf).await {
-            stdout.push_str(&String::from_utf8_lossy(&buf[..n]));
-        }
+    let hold_list = crate::commands::hold::read_hold_list(&app);
+    let (held, candidates): (Vec<_>, Vec<_>) = check_for_updates(app.clone(), state.clone())
+        .await?
+        .into_iter()
+        .partition(|pkg| hold_list.iter().any(|h| h.eq_ignore_ascii_case(&pkg.name)));
+    for pkg in &held {
+        log::info!("Skipping held package '{}' ({} → {})", pkg.name, pkg.current, pkg.available);
     }
-    
-    // Capture stderr
-    if let Some(mut err) = child.stderr.take() {
-        let mut buf = [0u8; 8192];
-        if let Ok(n) = err.read(&mut buf).await {
-            stderr.push_str(&String::from_utf8_lossy(&buf[..n]));
+    if candidates.is_empty() {
+        log::info!("Headless package update: nothing to update");
+        return Ok(HeadlessUpdateReport::default());
+    }
+
+    // Update only the outdated packages by name so that held packages are never touched.
+    let names: Vec<&str> = candidates.iter().map(|pkg| pkg.name.as_str()).collect();
+    let mut command = format!("scoop update {}", names.join(" "));
+    if scoop::use_global(&app, ScoopOp::UpdateAll)? {
+        command.push_str(" --global");
+    }
+    // Lines are written to the operation log as they arrive, even in silent mode
+    let operation_id = new_operation_id("headless-update");
+    log::info!(
+        "Headless update output is logged under operation '{}'",
+        operation_id
+    );
+    let (status, stdout, stderr) =
+        powershell::run_command_with_operation_log(&command, &operation_id).await?;
+    let mut up_to_date_lines = Vec::new();
+    for line in stdout.lines().chain(stderr.lines()).map(str::trim) {
+        if output::is_up_to_date(line) {
+            log::info!("scoop update: {}", line);
+            up_to_date_lines.push(line.to_lowercase());
+        } else if output::is_updated(line) {
+            log::info!("scoop update: {}", line);
+        } else if !line.is_empty() {
+            log::debug!("scoop update: {}", line);
         }
     }
-    
-    let status = child
-        .wait()
-        .await
-        .map_err(|e| format!("Failed to execute scoop update *: {}", e))?;
 
-    if !status.success() {
+    // The installed set has changed on disk, so re-read versions from there.
+    invalidate_installed_cache(state.clone()).await;
+    let scoop_path = scoop::apps_root(&app, &state);
+
+    let results: Vec<PackageUpdateResult> = candidates
+        .into_iter()
+        .filter_map(|pkg| {
+            let new_version = get_installed_version(&scoop_path, &pkg.name);
+            let status = match &new_version {
+                Some(v) if *v != pkg.current => PackageUpdateStatus::Updated,
+                // Scoop found nothing to do, e.g. the status check was stale
+                _ if reported_up_to_date(&up_to_date_lines, &pkg.name) => return None,
+                _ => PackageUpdateStatus::Failed,
+            };
+            Some(PackageUpdateResult {
+                name: pkg.name,
+                old_version: pkg.current,
+                new_version,
+                status,
+            })
+        })
+        .collect();
+
+    // A non-zero exit still leaves per-package results, so callers can tell a partial
+    // update from a complete failure
+    let error = (!status.success()).then(|| {
         log::warn!(
             "Headless update_all_packages exited with status: {}",
             status
         );
-        if !stdout.is_empty() {
-            log::debug!(
-                "Partial stdout: {}",
-                stdout.lines().take(20).collect::<Vec<_>>().join(" | ")
-            );
-        }
-
-        if !stderr.is_empty() {
-            log::debug!(
-                "Headless update stderr: {}",
-                stderr
-            );
-        }
-
-        // Return error details from stderr or stdout
-        let error_lines: Vec<String> = stderr
+        stderr
             .lines()
             .chain(stdout.lines())
             .filter(|line| !line.trim().is_empty())
             .take(10)
-            .map(|line| line.to_string())
-            .collect();
-
-        return Err(format!("Headless package update failed: {}", error_lines.join("; ")));
-    }
+            .collect::<Vec<_>>()
+            .join("; ")
+    });
 
-    // Parse output to extract update details
-    let update_lines: Vec<String> = stdout
-        .lines()
-        .filter(|line| {
-            let trimmed = line.trim();
-            !trimmed.is_empty() && (
-                trimmed.contains("Updating") || 
-                trimmed.contains("Updated") || 
-                trimmed.contains("up to date") ||
-                trimmed.contains("Installing") ||
-                trimmed.contains("Downloading") ||
-                trimmed.contains("Extracting") ||
-                trimmed.contains("Linking") ||
-                trimmed.contains("WARN") ||
-                trimmed.contains("ERROR")
-            )
-        })
-        .map(|line| line.trim().to_string())
-        .collect();
-
-    // Log the update details
-    for line in &update_lines {
+    let report = HeadlessUpdateReport { results, error };
+    for line in report.formatted_lines() {
         log::info!("{}", line);
     }
 
-    // If no meaningful output, add a summary
-    let result = if update_lines.is_empty() {
-        vec!["All packages are up to date.".to_string()]
-    } else {
-        update_lines
-    };
-
-    // Trigger auto cleanup after successful headless update
+    // Trigger auto cleanup after headless update
     trigger_auto_cleanup(app, state).await;
-    log::info!("Headless package update completed successfully");
-    Ok(result)
-}
\ No newline at end of file
+    log::info!(
+        "Headless package update completed: {} updated, {} failed",
+        report.updated_count(),
+        report.failed_count()
+    );
+    Ok(report)
+}
@@ -1,12 +1,10 @@
 use tauri::{command, AppHandle, Emitter};
-use std::path::PathBuf;
-use tauri_plugin_store::StoreExt;
 
 /// Get the current update channel from settings
 #[command]
 pub async fn get_update_channel(app_handle: AppHandle) -> Result<String, String> {
     // Use the same store that the frontend uses (settings.json)
-    let store = app_handle.store(PathBuf::from("settings.json"))
+    let store = crate::commands::settings::open_settings_store(&app_handle)
         .map_err(|e| format!("Failed to load store: {}", e))?;
     
     // Try to get the channel from frontend settings
@@ -0,0 +1,128 @@
+//! Persistent history of automatic bucket and package update runs.
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::{AppHandle, Manager};
+
+/// Settings key holding the list of update log entries (newest last).
+const UPDATE_LOG_KEY: &str = "updateLog";
+/// Settings key toggling whether update runs are recorded.
+pub const UPDATE_LOG_ENABLED_KEY: &str = "buckets.updateLogEnabled";
+/// Maximum number of entries kept in the store.
+const MAX_LOG_ENTRIES: usize = 100;
+/// An entry identical to the previous one within this many seconds is not recorded again.
+const DUPLICATE_WINDOW_SECS: u64 = 60;
+/// Number of entries returned by `get_update_log` when no limit is passed.
+const DEFAULT_LOG_LIMIT: usize = 50;
+
+/// A single recorded update run.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateLogEntry {
+    /// Unix timestamp (seconds) when the run finished.
+    pub timestamp: u64,
+    /// "bucket" or "package".
+    pub operation_type: String,
+    /// "success", "partial" or "failed".
+    pub operation_result: String,
+    pub success_count: usize,
+    pub total_count: usize,
+    /// Human-readable per-item lines (the same lines streamed to the UI).
+    pub details: Vec<String>,
+}
+
+impl UpdateLogEntry {
+    pub fn new(operation_type: &str, success_count: usize, total_count: usize, details: Vec<String>) -> Self {
+        let operation_result = if success_count == total_count {
+            "success"
+        } else if success_count == 0 {
+            "failed"
+        } else {
+            "partial"
+        };
+
+        Self {
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            operation_type: operation_type.to_string(),
+            operation_result: operation_result.to_string(),
+            success_count,
+            total_count,
+            details,
+        }
+    }
+
+    /// Returns true if `self` repeats `previous` shortly after it, e.g. when the
+    /// scheduler retries a failing run.
+    fn is_duplicate_of(&self, previous: &UpdateLogEntry) -> bool {
+        self.operation_type == previous.operation_type
+            && self.operation_result == previous.operation_result
+            && self.details == previous.details
+            && self.timestamp.saturating_sub(previous.timestamp) <= DUPLICATE_WINDOW_SECS
+    }
+}
+
+/// Reads all stored update log entries, oldest first.
+pub fn read_update_log(app: &AppHandle) -> Vec<UpdateLogEntry> {
+    crate::commands::settings::read_config_list(app, UPDATE_LOG_KEY)
+}
+
+/// Appends an entry to the update log unless logging has been disabled in settings.
+pub fn add_log_entry_if_enabled(app: &AppHandle, entry: UpdateLogEntry) {
+    let enabled = crate::commands::settings::get_config_value(
+        app.clone(),
+        UPDATE_LOG_ENABLED_KEY.to_string(),
+    )
+    .ok()
+    .flatten()
+    .and_then(|v| v.as_bool())
+    .unwrap_or(true);
+
+    if !enabled {
+        return;
+    }
+
+    if app.state::<crate::state::AppState>().is_shutting_down() {
+        log::info!("Skipping update log write during shutdown");
+        return;
+    }
+
+    if read_update_log(app)
+        .last()
+        .map_or(false, |last| entry.is_duplicate_of(last))
+    {
+        log::debug!(
+            "Skipping duplicate {} update log entry ({})",
+            entry.operation_type,
+            entry.operation_result
+        );
+        return;
+    }
+    if let Err(e) =
+        crate::commands::settings::append_config_list(app, UPDATE_LOG_KEY, entry, MAX_LOG_ENTRIES)
+    {
+        log::warn!("Failed to write update log entry: {}", e);
+    }
+}
+
+/// Returns the most recent update log entries, newest first.
+#[tauri::command]
+pub fn get_update_log(app: AppHandle, limit: Option<usize>) -> Vec<UpdateLogEntry> {
+    read_update_log(&app)
+        .into_iter()
+        .rev()
+        .take(limit.unwrap_or(DEFAULT_LOG_LIMIT))
+        .collect()
+}
+
+/// Removes all update log entries.
+#[tauri::command]
+pub fn clear_update_log(app: AppHandle) -> Result<(), String> {
+    log::info!("Clearing update log");
+    crate::commands::settings::set_config_value(
+        app,
+        UPDATE_LOG_KEY.to_string(),
+        Value::Array(Vec::new()),
+    )
+}
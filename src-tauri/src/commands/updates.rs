@@ -5,7 +5,7 @@ use crate::state::AppState;
 use crate::utils::locate_package_manifest;
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::Path;
 use tauri::{AppHandle, Runtime, State};
@@ -18,6 +18,15 @@ pub struct UpdatablePackage {
     pub available: String,
 }
 
+/// A package that would be updated, as shown on the "review updates" screen.
+#[derive(Serialize, Debug, Clone)]
+pub struct OutdatedPackage {
+    pub name: String,
+    pub current_version: String,
+    pub available_version: String,
+    pub bucket: String,
+}
+
 /// Represents the structure of a `manifest.json` file, used to extract the version.
 #[derive(Deserialize, Debug)]
 struct Manifest {
@@ -108,3 +117,37 @@ pub async fn check_for_updates<R: Runtime>(
     log::info!("Found {} updatable packages", updatable_packages.len());
     Ok(updatable_packages)
 }
+
+/// Lists the packages an update run would touch, without updating anything.
+///
+/// Besides the packages held through Scoop, packages on the app-level hold list are
+/// excluded, matching what `update_all_packages_headless` would skip.
+#[tauri::command]
+pub async fn check_outdated<R: Runtime>(
+    app: AppHandle<R>,
+    state: State<'_, AppState>,
+) -> Result<Vec<OutdatedPackage>, String> {
+    let hold_list = crate::commands::hold::read_hold_list(&app);
+
+    let buckets: HashMap<String, String> = get_installed_packages_full(app.clone(), state.clone())
+        .await?
+        .into_iter()
+        .map(|p| (p.name, p.source))
+        .collect();
+
+    let mut outdated: Vec<OutdatedPackage> = check_for_updates(app, state)
+        .await?
+        .into_iter()
+        .filter(|p| !hold_list.iter().any(|h| h.eq_ignore_ascii_case(&p.name)))
+        .map(|p| OutdatedPackage {
+            bucket: buckets.get(&p.name).cloned().unwrap_or_default(),
+            name: p.name,
+            current_version: p.current,
+            available_version: p.available,
+        })
+        .collect();
+    outdated.sort_by(|a, b| a.name.cmp(&b.name));
+
+    log::info!("Found {} outdated packages (excluding held)", outdated.len());
+    Ok(outdated)
+}
@@ -62,11 +62,7 @@ pub async fn scan_package(
             log::info!("virustotal stdout: {}", &line);
             if let Err(e) = window_clone.emit(
                 "operation-output",
-                powershell::StreamOutput {
-                    line,
-                    source: "stdout".to_string(),
-                    operation_id: None,
-                },
+                powershell::StreamOutput::new(line, "stdout", None),
             ) {
                 log::error!("Failed to emit stdout event: {}", e);
             }
@@ -79,11 +75,7 @@ pub async fn scan_package(
             log::error!("virustotal stderr: {}", &line);
             if let Err(e) = window_clone.emit(
                 "operation-output",
-                powershell::StreamOutput {
-                    line,
-                    source: "stderr".to_string(),
-                    operation_id: None,
-                },
+                powershell::StreamOutput::new(line, "stderr", None),
             ) {
                 log::error!("Failed to emit stderr event: {}", e);
             }
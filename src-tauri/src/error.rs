@@ -0,0 +1,77 @@
+//! Structured error type returned by Tauri commands.
+//!
+//! Errors serialize as `{ "code": "...", "message": "..." }` so the frontend can branch
+//! on `code`, while `Display` keeps the plain message that commands used to return.
+//!
+//! `String` errors from existing helpers are not converted implicitly; call sites pick
+//! the variant with `map_err` so the code stays meaningful.
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AppError {
+    /// Scoop (or one of its directories) could not be found.
+    ScoopNotFound(String),
+    /// A Scoop or system operation ran but did not succeed.
+    OperationFailed(String),
+    /// A filesystem or other I/O error.
+    Io(String),
+    /// A file is in use and could not be modified.
+    LockedFile(String),
+    /// The caller passed an invalid argument.
+    InvalidInput(String),
+}
+
+impl AppError {
+    /// Stable identifier of the error category, used by the frontend.
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::ScoopNotFound(_) => "SCOOP_NOT_FOUND",
+            AppError::OperationFailed(_) => "OPERATION_FAILED",
+            AppError::Io(_) => "IO",
+            AppError::LockedFile(_) => "LOCKED_FILE",
+            AppError::InvalidInput(_) => "INVALID_INPUT",
+        }
+    }
+
+    /// The human-readable message.
+    pub fn message(&self) -> &str {
+        match self {
+            AppError::ScoopNotFound(msg)
+            | AppError::OperationFailed(msg)
+            | AppError::Io(msg)
+            | AppError::LockedFile(msg)
+            | AppError::InvalidInput(msg) => msg,
+        }
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.message())
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl Serialize for AppError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("AppError", 2)?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("message", self.message())?;
+        state.end()
+    }
+}
+
+impl From<std::io::Error> for AppError {
+    fn from(err: std::io::Error) -> Self {
+        AppError::Io(err.to_string())
+    }
+}
+
+impl From<AppError> for String {
+    fn from(err: AppError) -> Self {
+        err.to_string()
+    }
+}
@@ -1,6 +1,7 @@
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
 mod cold_start;
 mod commands;
+mod error;
 mod models;
 mod scheduler;
 mod state;
@@ -10,6 +11,7 @@ mod i18n;
 
 use crate::commands::settings::detect_scoop_path;
 use std::path::PathBuf;
+use std::time::Instant;
 use tauri::{Manager, WindowEvent};
 use tauri_plugin_log::{Target, TargetKind};
 
@@ -74,7 +76,8 @@ pub fn run() {
     let mut builder = tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_dialog::init())
-        .plugin(tauri_plugin_process::init());
+        .plugin(tauri_plugin_process::init())
+        .plugin(tauri_plugin_notification::init());
 
     // Add single instance plugin only on Windows
     #[cfg(windows)]
@@ -94,8 +97,6 @@ pub fn run() {
         .map(|dir| dir.join("com.rscoop.app").join("logs"))
         .unwrap_or_else(|| PathBuf::from("./logs"));
 
-    cleanup_old_logs(&log_dir);
-
     // Create log directory if it does not exist
     if let Err(e) = std::fs::create_dir_all(&log_dir) {
         eprintln!("Failed to create log directory {:?}: {}", log_dir, e);
@@ -140,24 +141,44 @@ pub fn run() {
         })
         .setup(|app| {
             // Windows-specific setup
+            let started = Instant::now();
             #[cfg(windows)]
             setup_windows_specific(app)?;
+            let platform_setup = started.elapsed();
 
             // Resolve Scoop path
+            let started = Instant::now();
             let scoop_path = resolve_scoop_path(app.handle().clone())?;
             app.manage(state::AppState::new(scoop_path));
+            cold_start::record_startup_phase(app.handle(), "platform setup", platform_setup);
+            cold_start::record_startup_phase(app.handle(), "resolve scoop path", started.elapsed());
+
+            // Move data left in the legacy rscoop directory (runs once)
+            let started = Instant::now();
+            if let Err(e) = commands::debug::migrate_legacy_data() {
+                log::warn!("Failed to migrate legacy data: {}", e);
+            }
+
+            // Apply the configured log retention policy
+            commands::debug::prune_old_logs_from_settings(app.handle());
+            cold_start::record_startup_phase(app.handle(), "data maintenance", started.elapsed());
 
             // Show the main application window
+            let started = Instant::now();
             show_main_window(app)?;
 
             // Setup system tray
             if let Err(e) = tray::setup_system_tray(&app.handle()) {
                 log::error!("Failed to setup system tray: {}", e);
             }
+            cold_start::record_startup_phase(app.handle(), "window and tray", started.elapsed());
 
 
             // Start background tasks
             scheduler::start_background_tasks(app.handle().clone());
+            if let Err(e) = commands::apps_watcher::start_watching(app.handle()) {
+                log::warn!("Apps watcher not started: {}", e);
+            }
 
             Ok(())
         })
@@ -167,20 +188,50 @@ pub fn run() {
         })
         .invoke_handler(tauri::generate_handler![
             commands::search::search_scoop,
+            commands::search::get_manifest_cache_stats,
             commands::installed::get_installed_packages_full,
             commands::installed::refresh_installed_packages,
             commands::installed::get_package_path,
+            commands::installed::recompute_fingerprint,
+            commands::installed::refresh_package,
             commands::info::get_package_info,
+            commands::info::open_app_directory,
             commands::install::install_package,
+            commands::install::install_from_manifest,
+            commands::install::reinstall_package,
             commands::manifest::get_package_manifest,
+            commands::manifest::get_manifest,
+            commands::manifest::list_available_versions,
+            commands::manifest::estimate_install_size,
+            commands::manifest::validate_manifest,
             commands::updates::check_for_updates,
+            commands::updates::check_outdated,
             commands::update::update_package,
             commands::update::update_all_packages,
+            commands::update::run_scheduled_update_now,
+            commands::update::rollback_package,
             commands::uninstall::uninstall_package,
+            commands::uninstall::uninstall_packages,
+            commands::uninstall::undo_last_uninstall,
             commands::uninstall::clear_package_cache,
             commands::status::check_scoop_status,
+            commands::status::check_scoop_installation,
             commands::settings::get_config_value,
             commands::settings::set_config_value,
+            commands::settings::save_settings_store,
+            commands::settings::set_scheduler_paused,
+            commands::settings::reset_update_schedule,
+            commands::settings::get_auto_update_packages,
+            commands::settings::set_auto_update_packages,
+            commands::settings::get_silent_update_enabled,
+            commands::settings::set_silent_update_enabled,
+            commands::settings::get_auto_update_interval,
+            commands::settings::set_auto_update_interval,
+            commands::settings::set_use_global,
+            commands::apps_watcher::start_apps_watcher,
+            commands::apps_watcher::stop_apps_watcher,
+            commands::settings::export_settings,
+            commands::settings::import_settings,
             commands::settings::get_scoop_path,
             commands::settings::set_scoop_path,
             commands::settings::get_virustotal_api_key,
@@ -188,34 +239,63 @@ pub fn run() {
             commands::settings::get_scoop_proxy,
             commands::settings::set_scoop_proxy,
             commands::settings::detect_scoop_path,
+            commands::settings::detect_scoop_installations,
             commands::settings::validate_scoop_directory,
             commands::settings::run_scoop_command,
             commands::settings::run_powershell_command,
             commands::settings::get_scoop_config,
             commands::settings::update_scoop_config,
+            commands::settings::set_scoop_config,
             commands::settings::get_scoop_config_directory,
             commands::virustotal::scan_package,
             commands::auto_cleanup::run_auto_cleanup,
+            commands::auto_cleanup::get_auto_cleanup_status,
+            commands::auto_cleanup::set_auto_cleanup_disabled,
             commands::doctor::checkup::run_scoop_checkup,
+            commands::doctor::report::run_doctor,
             commands::doctor::cleanup::cleanup_all_apps,
             commands::doctor::cleanup::cleanup_all_apps_force,
+            commands::doctor::cleanup::list_old_versions,
+            commands::doctor::cleanup::remove_version,
             commands::doctor::cleanup::cleanup_outdated_cache,
             commands::doctor::cache::list_cache_contents,
+            commands::doctor::cache::verify_cache,
+            commands::doctor::cache::get_cache_summary,
+            commands::doctor::disk_usage::get_disk_usage,
+            commands::doctor::disk_usage::recommend_cleanup,
             commands::doctor::cache::clear_cache,
             commands::doctor::shim::list_shims,
             commands::doctor::shim::remove_shim,
             commands::doctor::shim::alter_shim,
             commands::doctor::shim::add_shim,
+            commands::doctor::shim::repair_shims,
+            commands::doctor::shim::find_dangling_shims,
+            commands::doctor::shim::remove_dangling_shims,
             commands::hold::list_held_packages,
             commands::hold::hold_package,
             commands::hold::unhold_package,
+            commands::hold::scoop_hold_package,
+            commands::hold::scoop_unhold_package,
+            commands::hold::get_hold_list,
+            commands::operation_history::get_operation_history,
+            commands::update_log::get_update_log,
+            commands::update_log::clear_update_log,
+            commands::operation_history::get_active_operations,
+            commands::operation_history::get_last_operation,
+            commands::hold::add_to_hold_list,
+            commands::hold::remove_from_hold_list,
             commands::bucket::get_buckets,
             commands::bucket::get_bucket_info,
             commands::bucket::get_bucket_manifests,
+            commands::bucket::find_bucket_conflicts,
             commands::bucket_install::install_bucket,
             commands::bucket_install::validate_bucket_install,
             commands::bucket_install::update_bucket,
+            commands::bucket_install::update_buckets,
+            commands::bucket_install::get_bucket_status,
             commands::bucket_install::remove_bucket,
+            commands::bucket_install::scoop_add_bucket,
+            commands::bucket_install::scoop_remove_bucket,
             commands::bucket_search::search_buckets,
             // commands::bucket_search::get_expanded_search_info,
             commands::bucket_search::get_default_buckets,
@@ -230,21 +310,41 @@ pub fn run() {
             commands::linker::debug_package_structure,
             commands::linker::change_package_bucket,
             commands::debug::get_debug_info,
+            commands::debug::get_startup_timings,
             commands::debug::get_app_logs,
             commands::debug::read_app_log_file,
+            commands::debug::list_log_files,
+            commands::debug::read_operation_log,
+            commands::debug::read_log_file,
+            commands::debug::start_log_tail,
+            commands::debug::stop_log_tail,
+            commands::debug::create_support_bundle,
             commands::debug::get_app_data_dir,
+            commands::debug::migrate_legacy_data,
             commands::debug::get_log_dir_cmd,
+            commands::debug::reveal_in_explorer,
             commands::debug::get_log_retention_days,
             commands::debug::set_log_retention_days,
+            commands::debug::get_log_retention,
+            commands::debug::set_log_retention,
             commands::debug::check_factory_reset_marker,
+            commands::debug::pending_restart_reasons,
             commands::debug::clear_application_data,
             commands::debug::clear_store_data,
             commands::debug::clear_store_data,
+            commands::debug::prune_settings_backups,
+            commands::debug::clear_logs,
             commands::debug::clear_registry_data,
             commands::debug::clear_webview_cache,
+            commands::debug::clear_webview_cache_soft,
             commands::debug::factory_reset,
             commands::debug::final_cleanup_on_exit,
             commands::debug::perform_scheduled_webview_cleanup,
+            commands::debug::cancel_scheduled_webview_cleanup,
+            commands::dependencies::find_orphaned_packages,
+            commands::export::export_installed,
+            commands::export::diff_installed,
+            commands::export::import_installed,
             commands::version::check_and_update_version,
             commands::startup::is_auto_start_enabled,
             commands::startup::set_auto_start_enabled,
@@ -266,44 +366,15 @@ pub fn run() {
             commands::custom_update::download_and_install_custom_update,
             commands::custom_update::get_current_version
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
-}
-
-// Helper function: Clean up old log files in the specified directory
-fn cleanup_old_logs(log_dir: &PathBuf) {
-    if !log_dir.exists() {
-        return;
-    }
-
-    if let Ok(entries) = std::fs::read_dir(log_dir) {
-        let mut removed_count = 0;
-        let mut failed_count = 0;
-
-        for entry in entries.flatten() {
-            if let Ok(metadata) = entry.metadata() {
-                if metadata.is_file() {
-                    match std::fs::remove_file(entry.path()) {
-                        Ok(_) => removed_count += 1,
-                        Err(e) => {
-                            log::debug!("Failed to remove log file {:?}: {}", entry.path(), e);
-                            failed_count += 1;
-                        }
-                    }
-                }
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app, event| {
+            // Runs before the store plugin's save-on-exit, which would rewrite the
+            // settings file in place
+            if let tauri::RunEvent::ExitRequested { .. } = event {
+                commands::settings::release_settings_store(app);
             }
-        }
-
-        if removed_count > 0 || failed_count > 0 {
-            log::info!(
-                "Log cleanup completed: {} removed, {} failed",
-                removed_count,
-                failed_count
-            );
-        }
-    } else {
-        log::debug!("Could not read log directory: {:?}", log_dir);
-    }
+        });
 }
 
 // Windows-specific setup
@@ -36,6 +36,9 @@ pub struct ScoopPackage {
     pub match_source: MatchSource,
     #[serde(default)]
     pub is_versioned_install: bool,
+    /// Whether the package is on the app-level hold list (`packages.holdList`).
+    #[serde(default)]
+    pub is_held: bool,
 }
 
 // -----------------------------------------------------------------------------
@@ -87,6 +90,25 @@ pub struct ScoopStatus {
     pub is_everything_ok: bool,
 }
 
+/// Result of verifying that Scoop itself is installed and usable.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ScoopInstallationStatus {
+    pub scoop_path: String,
+    pub shim_exists: bool,
+    pub shims_on_path: bool,
+    pub version: Option<String>,
+    pub apps_dir_exists: bool,
+    pub buckets_dir_exists: bool,
+    pub is_installed: bool,
+    /// Drive type of the volume holding the scoop path ("fixed", "removable",
+    /// "network", "cdrom", "ram" or "unknown"). `None` when it cannot be determined.
+    #[serde(default)]
+    pub drive_type: Option<String>,
+    /// Free space available to the current user on that volume, in bytes.
+    #[serde(default)]
+    pub free_space_bytes: Option<u64>,
+}
+
 // -----------------------------------------------------------------------------
 // Manifest Types (from installed.rs)
 // -----------------------------------------------------------------------------
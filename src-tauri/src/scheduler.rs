@@ -1,4 +1,39 @@
+use crate::commands::powershell::{StreamOutput, EVENT_OUTPUT};
 use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_notification::NotificationExt;
+
+/// Auto-update interval: "off", "1h", "6h", "24h"/"1d", "7d"/"1w", "custom:<secs>" or seconds.
+pub const AUTO_UPDATE_INTERVAL_KEY: &str = "buckets.autoUpdateInterval";
+/// When true, packages are updated after each scheduled bucket refresh.
+pub const AUTO_UPDATE_PACKAGES_KEY: &str = "buckets.autoUpdatePackagesEnabled";
+/// When true, scheduled runs do not stream their progress to the UI.
+pub const SILENT_UPDATE_KEY: &str = "buckets.silentUpdateEnabled";
+/// Timestamp of the last completed run, from which the next due time is computed.
+pub const LAST_UPDATE_TS_KEY: &str = "buckets.lastAutoUpdateTs";
+/// Timestamp of the last run that was actually started (independent of its outcome).
+pub const LAST_RUN_TS_KEY: &str = "buckets.lastAutoRunTs";
+/// Minimum number of seconds between two actual runs, even when a run is overdue.
+pub const MIN_GAP_SECS_KEY: &str = "buckets.autoUpdateMinGapSecs";
+const DEFAULT_MIN_GAP_SECS: u64 = 600;
+/// When true, the scheduler keeps polling but skips all runs.
+pub const SCHEDULER_PAUSED_KEY: &str = "scheduler.paused";
+/// How often a paused scheduler re-checks its settings.
+const PAUSED_POLL_SECS: u64 = 60;
+/// When true, due runs are skipped while the network is unreachable. Off by default.
+pub const SKIP_WHEN_OFFLINE_KEY: &str = "scheduler.skipWhenOffline";
+/// Host probed by the connectivity check; most buckets are hosted there.
+const CONNECTIVITY_PROBE_ADDR: &str = "github.com:443";
+const CONNECTIVITY_TIMEOUT_SECS: u64 = 3;
+/// How long to wait before re-checking after an offline skip.
+const OFFLINE_RETRY_SECS: u64 = 120;
+/// Quiet-hours window (local "HH:MM") during which due runs are deferred.
+pub const QUIET_START_KEY: &str = "scheduler.quietStart";
+pub const QUIET_END_KEY: &str = "scheduler.quietEnd";
+/// When true, an OS notification summarizes each silent auto-update run.
+pub const NOTIFY_ON_SILENT_COMPLETE_KEY: &str = "scheduler.notifyOnSilentComplete";
+/// Do-not-disturb window (local "HH:MM") during which no notification is sent.
+pub const NOTIFY_DND_START_KEY: &str = "scheduler.notifyDndStart";
+pub const NOTIFY_DND_END_KEY: &str = "scheduler.notifyDndEnd";
 
 pub fn start_background_tasks(app: AppHandle) {
     use std::time::{Duration, SystemTime, UNIX_EPOCH};
@@ -6,12 +41,42 @@ pub fn start_background_tasks(app: AppHandle) {
 
     tauri::async_runtime::spawn(async move {
         log::info!("Background tasks started");
+        let mut was_paused = false;
 
         loop {
+            if app.state::<crate::state::AppState>().is_shutting_down() {
+                log::info!("Shutdown requested, stopping background tasks");
+                break;
+            }
+
+            let paused = crate::commands::settings::get_config_value(
+                app.clone(),
+                SCHEDULER_PAUSED_KEY.to_string(),
+            )
+            .ok()
+            .flatten()
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+            if paused {
+                if !was_paused {
+                    log::info!("Background tasks paused");
+                } else {
+                    log::debug!("Background tasks still paused");
+                }
+                was_paused = true;
+                sleep_or_wake(&app, Duration::from_secs(PAUSED_POLL_SECS)).await;
+                continue;
+            }
+            if was_paused {
+                log::info!("Background tasks resumed");
+                was_paused = false;
+            }
+
             // Parse auto-update interval from settings with better error handling
             let interval_raw = crate::commands::settings::get_config_value(
                 app.clone(),
-                "buckets.autoUpdateInterval".to_string(),
+                AUTO_UPDATE_INTERVAL_KEY.to_string(),
             )
             .ok()
             .flatten()
@@ -22,7 +87,7 @@ pub fn start_background_tasks(app: AppHandle) {
 
             if interval_secs.is_none() {
                 // Auto-update is disabled, check again later
-                sleep(Duration::from_secs(300)).await; // 5 minutes when auto-update is disabled
+                sleep_or_wake(&app, Duration::from_secs(300)).await; // 5 minutes when auto-update is disabled
                 continue;
             }
             let interval_secs = interval_secs.unwrap();
@@ -34,30 +99,76 @@ pub fn start_background_tasks(app: AppHandle) {
                 .as_secs();
             let last_ts = crate::commands::settings::get_config_value(
                 app.clone(),
-                "buckets.lastAutoUpdateTs".to_string(),
+                LAST_UPDATE_TS_KEY.to_string(),
             )
             .ok()
             .flatten()
             .and_then(|v| v.as_u64())
             .unwrap_or(0);
+            let last_ts = discard_future_ts(&app, LAST_UPDATE_TS_KEY, last_ts, now);
 
-            let elapsed = if last_ts == 0 {
-                interval_secs
-            } else {
-                now.saturating_sub(last_ts)
-            };
+            if should_run(last_ts, interval_secs, now) {
+                // Even when overdue, keep a minimum gap between actual runs so that
+                // repeatedly launching the app doesn't hammer remote bucket repos.
+                let last_run_ts = crate::commands::settings::get_config_value(
+                    app.clone(),
+                    LAST_RUN_TS_KEY.to_string(),
+                )
+                .ok()
+                .flatten()
+                .and_then(|v| v.as_u64())
+                .unwrap_or(0);
+                let last_run_ts = discard_future_ts(&app, LAST_RUN_TS_KEY, last_run_ts, now);
+                let min_gap_secs = crate::commands::settings::get_config_value(
+                    app.clone(),
+                    MIN_GAP_SECS_KEY.to_string(),
+                )
+                .ok()
+                .flatten()
+                .and_then(|v| v.as_u64())
+                .unwrap_or(DEFAULT_MIN_GAP_SECS);
+                let since_last_run = now.saturating_sub(last_run_ts);
+
+                if last_run_ts != 0 && since_last_run < min_gap_secs {
+                    let wait = min_gap_secs - since_last_run;
+                    log::debug!(
+                        "Auto-update overdue but last run was {}s ago (min gap {}s), waiting {}s",
+                        since_last_run,
+                        min_gap_secs,
+                        wait
+                    );
+                    sleep_or_wake(&app, Duration::from_secs(wait.min(60))).await;
+                    continue;
+                }
+
+                if let Some(wait) = quiet_hours_remaining(&app) {
+                    log::info!("Auto-update due during quiet hours, deferring for {}s", wait);
+                    sleep_or_wake(&app, Duration::from_secs(wait)).await;
+                    continue;
+                }
 
-            if elapsed >= interval_secs {
                 log::debug!(
                     "Auto-update interval elapsed ({}s), starting update check",
-                    elapsed
+                    interval_secs
                 );
-                run_auto_update(&app, now).await;
+                if skip_when_offline(&app) && !is_online().await {
+                    log::info!(
+                        "Auto-update due but network is unreachable, retrying in {}s",
+                        OFFLINE_RETRY_SECS
+                    );
+                    sleep_or_wake(&app, Duration::from_secs(OFFLINE_RETRY_SECS)).await;
+                    continue;
+                }
+
+                if let Err(e) = run_guarded_update_cycle(&app, now).await {
+                    log::info!("{}, skipping scheduler tick", e);
+                    sleep(Duration::from_secs(60)).await;
+                }
                 continue;
             }
 
             // Calculate sleep duration (check at most every 60 seconds)
-            let remaining = interval_secs - elapsed;
+            let remaining = interval_secs.saturating_sub(now.saturating_sub(last_ts));
             let sleep_duration =
                 Duration::from_secs(remaining.min(60)); // Check every minute at most
 
@@ -67,199 +178,495 @@ pub fn start_background_tasks(app: AppHandle) {
                 interval_secs,
                 remaining
             );
-            sleep(sleep_duration).await;
+            sleep_or_wake(&app, sleep_duration).await;
         }
     });
 }
 
-async fn run_auto_update(app_handle: &tauri::AppHandle, run_started_at: u64) {
+/// Sleeps for `duration`, returning early when a scheduler setting changes.
+async fn sleep_or_wake(app: &AppHandle, duration: std::time::Duration) {
+    let state = app.state::<crate::state::AppState>();
+    tokio::select! {
+        _ = tokio::time::sleep(duration) => {}
+        _ = state.scheduler_wakeup.notified() => {
+            log::debug!("Scheduler woken up by a settings change");
+        }
+    }
+}
+
+/// Outcome of one update cycle.
+#[derive(serde::Serialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CycleReport {
+    pub buckets_total: usize,
+    pub buckets_succeeded: usize,
+    /// Buckets whose contents changed.
+    pub buckets_changed: usize,
+    pub packages_updated: usize,
+    pub packages_failed: usize,
+    /// Set when the bucket update could not run at all.
+    pub error: Option<String>,
+}
+
+impl CycleReport {
+    /// Summarizes the per-bucket results of a bucket update.
+    fn from_bucket_results(
+        results: &[crate::commands::bucket_install::BucketInstallResult],
+    ) -> Self {
+        Self {
+            buckets_total: results.len(),
+            buckets_succeeded: results.iter().filter(|r| r.success).count(),
+            buckets_changed: results.iter().filter(|r| r.success && r.changed).count(),
+            ..Self::default()
+        }
+    }
+
+    /// True when every bucket and package was updated without error.
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+            && self.buckets_succeeded == self.buckets_total
+            && self.packages_failed == 0
+    }
+}
+
+/// Resets a stored timestamp that lies in the future, which happens when it was written
+/// while the system clock was wrong. Left alone, `now - ts` would stay 0 and the
+/// scheduler would never run again. Returns the timestamp to use.
+fn discard_future_ts(app: &AppHandle, key: &str, ts: u64, now: u64) -> u64 {
+    if ts <= now {
+        return ts;
+    }
+    log::warn!(
+        "{} is {}s in the future (clock change?), treating the update as overdue",
+        key,
+        ts - now
+    );
+    save_config_unless_shutting_down(app, key, serde_json::json!(0));
+    0
+}
+
+/// Whether a run is due, given the last run timestamp (0 = never) and the interval.
+fn should_run(last_ts: u64, interval_secs: u64, now: u64) -> bool {
+    last_ts == 0 || now.saturating_sub(last_ts) >= interval_secs
+}
+
+/// Runs `run_update_cycle` under the bucket update lock, recording the run timestamps.
+/// The lock is released once the buckets are updated, before any package update.
+///
+/// Used by the scheduler loop once the interval has elapsed and by
+/// `run_scheduled_update_now`. Fails without doing anything if another bucket update
+/// is already in progress.
+pub(crate) async fn run_guarded_update_cycle(
+    app: &AppHandle,
+    run_started_at: u64,
+) -> Result<CycleReport, String> {
+    let state = app.state::<crate::state::AppState>();
+    let guard = state
+        .bucket_update_lock
+        .try_lock()
+        .map_err(|_| "Bucket update already in progress".to_string())?;
+
+    let silent =
+        crate::commands::settings::get_config_value(app.clone(), SILENT_UPDATE_KEY.to_string())
+            .ok()
+            .flatten()
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+
+    save_config_unless_shutting_down(app, LAST_RUN_TS_KEY, serde_json::json!(run_started_at));
+    state.set_scheduler_busy(true);
+    let report = run_update_cycle(app, &state, silent, guard).await;
+    state.set_scheduler_busy(false);
+
+    // Saved even when the update failed, to avoid frequent retries
+    save_last_update_ts(app, run_started_at);
+
+    if silent && report.error.is_none() {
+        notify_silent_update_complete(app, report.packages_updated, report.buckets_changed);
+    }
+    Ok(report)
+}
+
+/// Runs one update cycle: the bucket update and, if enabled, the package update that
+/// follows it, writing the update log entries and, unless `silent`, the UI events.
+///
+/// `bucket_guard` is dropped after the bucket update, so manual bucket updates are not
+/// refused for the whole package update.
+async fn run_update_cycle(
+    app: &AppHandle,
+    state: &crate::state::AppState,
+    silent: bool,
+    bucket_guard: tokio::sync::MutexGuard<'_, ()>,
+) -> CycleReport {
     log::info!("Starting auto bucket update task");
 
-    // Check if silent update is enabled
-    let silent_update_enabled = crate::commands::settings::get_config_value(
-        app_handle.clone(),
-        "buckets.silentUpdateEnabled".to_string(),
+    if !silent {
+        emit_start(
+            app,
+            "Updating buckets...",
+            "Starting automatic bucket update...",
+        );
+    }
+
+    let results = match crate::commands::bucket_install::update_all_buckets(app.clone()).await {
+        Ok(results) => results,
+        Err(e) => {
+            log::warn!("Auto bucket update failed: {}", e);
+            let error_line = format!("Error: {}", e);
+            crate::commands::update_log::add_log_entry_if_enabled(
+                app,
+                crate::commands::update_log::UpdateLogEntry::new(
+                    "bucket",
+                    0,
+                    1,
+                    vec![error_line.clone()],
+                ),
+            );
+            // Failures are always shown, even for silent runs
+            emit_finished(
+                app,
+                &[(error_line, false)],
+                false,
+                format!("Bucket update failed: {}", e),
+            );
+            return CycleReport {
+                error: Some(e),
+                ..CycleReport::default()
+            };
+        }
+    };
+
+    let mut report = CycleReport::from_bucket_results(&results);
+    log::info!(
+        "Auto bucket update completed: {}/{} succeeded",
+        report.buckets_succeeded,
+        report.buckets_total
+    );
+
+    // Build the per-bucket lines once; they feed both the update log and the UI.
+    let update_details: Vec<String> = results.iter().map(format_bucket_result).collect();
+    crate::commands::update_log::add_log_entry_if_enabled(
+        app,
+        crate::commands::update_log::UpdateLogEntry::new(
+            "bucket",
+            report.buckets_succeeded,
+            report.buckets_total,
+            update_details.clone(),
+        ),
+    );
+
+    if !silent {
+        // Unchanged buckets are left out to keep the console quiet
+        let lines: Vec<(String, bool)> = results
+            .iter()
+            .zip(update_details)
+            .filter(|(result, _)| result.changed || !result.success)
+            .map(|(result, line)| (line, result.success))
+            .collect();
+        let commits_pulled: u32 = results.iter().map(|r| r.commits_pulled).sum();
+        emit_finished(
+            app,
+            &lines,
+            report.buckets_succeeded == report.buckets_total,
+            format!(
+                "Bucket update completed: {} of {} succeeded, {} updated with {} new commits",
+                report.buckets_succeeded,
+                report.buckets_total,
+                report.buckets_changed,
+                commits_pulled
+            ),
+        );
+    }
+
+    drop(bucket_guard);
+
+    let auto_update_packages = crate::commands::settings::get_config_value(
+        app.clone(),
+        AUTO_UPDATE_PACKAGES_KEY.to_string(),
     )
     .ok()
     .flatten()
     .and_then(|v| v.as_bool())
     .unwrap_or(false);
 
-    // Notify UI that the update process is starting only if not silent update
-    if !silent_update_enabled {
-        if let Some(window) = app_handle.get_webview_window("main") {
-            let _ = window.emit("auto-operation-start", "Updating buckets...");
-            let _ = window.emit(
-                "operation-output",
-                serde_json::json!({
-                    "line": "Starting automatic bucket update...",
-                    "source": "stdout"
-                }),
-            );
+    if auto_update_packages && !state.is_shutting_down() {
+        let (updated, failed) = update_packages_after_buckets(app, silent).await;
+        report.packages_updated = updated;
+        report.packages_failed = failed;
+    }
+    report
+}
+
+/// Tells the UI that an automatic operation started.
+fn emit_start(app: &AppHandle, operation: &str, first_line: &str) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.emit("auto-operation-start", operation);
+        let _ = window.emit(EVENT_OUTPUT, StreamOutput::new(first_line, "stdout", None));
+    }
+}
+
+/// Streams `(line, success)` pairs to the UI and finishes the automatic operation.
+fn emit_finished(app: &AppHandle, lines: &[(String, bool)], success: bool, message: String) {
+    if let Some(window) = app.get_webview_window("main") {
+        for (line, ok) in lines {
+            let source = if *ok { "stdout" } else { "stderr" };
+            let _ = window.emit(EVENT_OUTPUT, StreamOutput::new(line.clone(), source, None));
         }
+        let _ = window.emit(
+            "operation-finished",
+            serde_json::json!({
+                "success": success,
+                "message": message
+            }),
+        );
     }
+}
 
-    // Update Buckets
-    match crate::commands::bucket_install::update_all_buckets().await {
-        Ok(results) => {
-            let successes = results.iter().filter(|r| r.success).count();
-            log::info!(
-                "Auto bucket update completed: {}/{} succeeded",
-                successes,
-                results.len()
-            );
+/// Formats a single bucket update result for the UI stream and the update log.
+fn format_bucket_result(result: &crate::commands::bucket_install::BucketInstallResult) -> String {
+    if result.success && result.changed {
+        format!(
+            "✓ Updated bucket: {} ({} new commits)",
+            result.bucket_name, result.commits_pulled
+        )
+    } else if result.success {
+        format!("✓ Bucket already up to date: {}", result.bucket_name)
+    } else {
+        format!("✗ Failed to update {}: {}", result.bucket_name, result.message)
+    }
+}
 
-            // Send result to UI, also fix emit.
-            if let Some(window) = app_handle.get_webview_window("main") {
-                for result in &results {
-                    let line = if result.success {
-                        format!("✓ Updated bucket: {}", result.bucket_name)
-                    } else {
-                        format!(
-                            "✗ Failed to update {}: {}",
-                            result.bucket_name, result.message
-                        )
-                    };
-
-                    let _ = window.emit(
-                        "operation-output",
-                        serde_json::json!({
-                            "line": line.clone(),
-                            "source": if result.success { "stdout" } else { "stderr" }
-                        }),
-                    );
-                }
+/// Writes a setting unless the app is shutting down, in which case the store
+/// files may already have been removed by `final_cleanup_on_exit`.
+fn save_config_unless_shutting_down(app_handle: &tauri::AppHandle, key: &str, value: serde_json::Value) {
+    if app_handle.state::<crate::state::AppState>().is_shutting_down() {
+        log::info!("Skipping {} write during shutdown", key);
+        return;
+    }
+    let _ = crate::commands::settings::set_config_value(app_handle.clone(), key.to_string(), value);
+}
 
-                let _ = window.emit("operation-finished", serde_json::json!({
-                    "success": successes == results.len(),
-                    "message": format!("Bucket update completed: {} of {} succeeded", successes, results.len())
-                }));
-            }
+/// Persists the last auto-update timestamp.
+fn save_last_update_ts(app_handle: &tauri::AppHandle, ts: u64) {
+    save_config_unless_shutting_down(app_handle, LAST_UPDATE_TS_KEY, serde_json::json!(ts));
+}
 
-            // Save the last update time
-            let _ = crate::commands::settings::set_config_value(
-                app_handle.clone(),
-                "buckets.lastAutoUpdateTs".to_string(),
-                serde_json::json!(run_started_at),
-            );
+/// Sends an OS notification summarizing a silent run, if `scheduler.notifyOnSilentComplete`
+/// is enabled, anything changed and the current local time is outside the do-not-disturb
+/// window.
+fn notify_silent_update_complete(app_handle: &tauri::AppHandle, packages: usize, buckets: usize) {
+    if packages == 0 && buckets == 0 {
+        return;
+    }
 
-            // Check if packages need update
-            let auto_update_packages = crate::commands::settings::get_config_value(
-                app_handle.clone(),
-                "buckets.autoUpdatePackagesEnabled".to_string(),
-            )
+    let read = |key: &str| {
+        crate::commands::settings::get_config_value(app_handle.clone(), key.to_string())
             .ok()
             .flatten()
-            .and_then(|v| v.as_bool())
-            .unwrap_or(false);
+    };
 
-            if auto_update_packages {
-                update_packages_after_buckets(app_handle, silent_update_enabled).await;
-            }
+    if !read(NOTIFY_ON_SILENT_COMPLETE_KEY)
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+    {
+        return;
+    }
+
+    let dnd_start = read(NOTIFY_DND_START_KEY).and_then(|v| v.as_str().and_then(parse_hhmm));
+    let dnd_end = read(NOTIFY_DND_END_KEY).and_then(|v| v.as_str().and_then(parse_hhmm));
+    if let (Some(start), Some(end)) = (dnd_start, dnd_end) {
+        if is_within_window(chrono::Local::now().time(), start, end) {
+            log::info!("Skipping silent update notification during do-not-disturb window");
+            return;
         }
-        Err(e) => {
-            log::warn!("Auto bucket update failed: {}", e);
+    }
 
-            if let Some(window) = app_handle.get_webview_window("main") {
-                let _ = window.emit(
-                    "operation-output",
-                    serde_json::json!({
-                        "line": format!("Error: {}", e),
-                        "source": "stderr"
-                    }),
-                );
+    let language = read("settings.language")
+        .and_then(|v| v.as_str().map(|s| s.to_string()))
+        .unwrap_or_else(|| "en".to_string());
+    let strings = crate::i18n::load_tray_locale_strings(app_handle, &language)
+        .unwrap_or(serde_json::Value::Null);
+    let title = strings
+        .get("silentUpdateTitle")
+        .and_then(|v| v.as_str())
+        .unwrap_or("Rscoop - Updates Installed");
+    let body = strings
+        .get("silentUpdateMessage")
+        .and_then(|v| v.as_str())
+        .unwrap_or("Updated {packages} packages and {buckets} buckets in the background")
+        .replace("{packages}", &packages.to_string())
+        .replace("{buckets}", &buckets.to_string());
+    if let Err(e) = app_handle
+        .notification()
+        .builder()
+        .title(title)
+        .body(&body)
+        .show()
+    {
+        log::warn!("Failed to show silent update notification: {}", e);
+    }
+}
 
-                let _ = window.emit(
-                    "operation-finished",
-                    serde_json::json!({
-                        "success": false,
-                        "message": format!("Bucket update failed: {}", e)
-                    }),
-                );
-            }
+/// Reads `scheduler.skipWhenOffline`. Off by default: the probe is a direct connection
+/// that ignores Scoop's proxy, so it would report users behind a proxy as offline.
+fn skip_when_offline(app: &AppHandle) -> bool {
+    crate::commands::settings::get_config_value(app.clone(), SKIP_WHEN_OFFLINE_KEY.to_string())
+        .ok()
+        .flatten()
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false)
+}
 
-            // keep the timestamp to avoid frequent retries even if it fails
-            let _ = crate::commands::settings::set_config_value(
-                app_handle.clone(),
-                "buckets.lastAutoUpdateTs".to_string(),
-                serde_json::json!(run_started_at),
-            );
+/// Quick connectivity check: resolves and connects to the probe host with a short timeout.
+async fn is_online() -> bool {
+    let connect = tokio::net::TcpStream::connect(CONNECTIVITY_PROBE_ADDR);
+    let timeout = std::time::Duration::from_secs(CONNECTIVITY_TIMEOUT_SECS);
+    match tokio::time::timeout(timeout, connect).await {
+        Ok(Ok(_)) => true,
+        Ok(Err(e)) => {
+            log::debug!("Connectivity check failed: {}", e);
+            false
+        }
+        Err(_) => {
+            log::debug!("Connectivity check timed out");
+            false
         }
     }
 }
 
-async fn update_packages_after_buckets(app_handle: &tauri::AppHandle, silent_update_enabled: bool) {
+/// Returns the number of seconds until the quiet-hours window ends, or `None` if no
+/// window is configured or the current local time is outside of it.
+fn quiet_hours_remaining(app: &AppHandle) -> Option<u64> {
+    let read = |key: &str| {
+        crate::commands::settings::get_config_value(app.clone(), key.to_string())
+            .ok()
+            .flatten()
+            .and_then(|v| v.as_str().and_then(parse_hhmm))
+    };
+    let (start, end) = (read(QUIET_START_KEY)?, read(QUIET_END_KEY)?);
+
+    let now = chrono::Local::now().time();
+    if !is_within_window(now, start, end) {
+        return None;
+    }
+    // Wrapping subtraction handles windows that cross midnight
+    let remaining = (end - now).num_seconds().rem_euclid(24 * 60 * 60);
+    Some(remaining.max(1) as u64)
+}
+
+/// Parses a local "HH:MM" time of day.
+fn parse_hhmm(value: &str) -> Option<chrono::NaiveTime> {
+    chrono::NaiveTime::parse_from_str(value.trim(), "%H:%M").ok()
+}
+
+/// Returns true if `now` falls inside `[start, end)`, where a window whose end is not
+/// after its start crosses midnight.
+fn is_within_window(
+    now: chrono::NaiveTime,
+    start: chrono::NaiveTime,
+    end: chrono::NaiveTime,
+) -> bool {
+    if start < end {
+        now >= start && now < end
+    } else {
+        now >= start || now < end
+    }
+}
+
+/// Updates all packages after a bucket refresh, returning the number of updated and
+/// failed packages.
+async fn update_packages_after_buckets(
+    app_handle: &tauri::AppHandle,
+    silent_update_enabled: bool,
+) -> (usize, usize) {
     log::info!("Starting auto package update after bucket refresh");
 
     // Notify UI that package update is starting only if not silent update
     if !silent_update_enabled {
-        if let Some(window) = app_handle.get_webview_window("main") {
-            let _ = window.emit("auto-operation-start", "Updating packages...");
-            let _ = window.emit(
-                "operation-output",
-                serde_json::json!({
-                    "line": "Starting automatic package update...",
-                    "source": "stdout"
-                }),
-            );
-        }
+        emit_start(
+            app_handle,
+            "Updating packages...",
+            "Starting automatic package update...",
+        );
     }
 
     let state = app_handle.state::<crate::state::AppState>();
     match crate::commands::update::update_all_packages_headless(app_handle.clone(), state).await {
-        Ok(update_details) => {
-            // Notify UI of success only if not silent update
+        Ok(report) => {
+            let updated = report.updated_count();
+            // A non-zero scoop exit counts as a failure even when no package result was parsed
+            let failed = report.failed_count() + usize::from(report.error.is_some());
+            log::info!(
+                "Auto package update completed: {} updated, {} failed",
+                updated,
+                failed
+            );
+
+            let update_details = report.formatted_lines();
+            crate::commands::update_log::add_log_entry_if_enabled(
+                app_handle,
+                crate::commands::update_log::UpdateLogEntry::new(
+                    "package",
+                    updated,
+                    updated + failed,
+                    update_details.clone(),
+                ),
+            );
+
+            // Notify UI of the result only if not silent update
             if !silent_update_enabled {
-                if let Some(window) = app_handle.get_webview_window("main") {
-                    for line in &update_details {
-                        let _ = window.emit(
-                            "operation-output",
-                            serde_json::json!({
-                                "line": line,
-                                "source": "stdout"
-                            }),
-                        );
-                    }
-
-                    let _ = window.emit(
-                        "operation-finished",
-                        serde_json::json!({
-                            "success": true,
-                            "message": "Automatic package update completed successfully"
-                        }),
-                    );
-                }
+                let message = if let Some(error) = &report.error {
+                    format!("Automatic package update failed: {}", error)
+                } else if report.results.is_empty() {
+                    "All packages are up to date".to_string()
+                } else {
+                    format!(
+                        "Automatic package update completed: {} updated, {} failed",
+                        updated, failed
+                    )
+                };
+                let error_line = report.error.as_ref().map(|e| format!("Error: {}", e));
+                let lines: Vec<(String, bool)> = update_details
+                    .into_iter()
+                    .map(|line| {
+                        let ok = error_line.as_ref() != Some(&line);
+                        (line, ok)
+                    })
+                    .collect();
+                emit_finished(app_handle, &lines, failed == 0, message);
             }
+            (updated, failed)
         }
         Err(e) => {
             log::warn!("Auto package headless update failed: {}", e);
             let error_line = format!("Error: {}", e);
 
+            crate::commands::update_log::add_log_entry_if_enabled(
+                app_handle,
+                crate::commands::update_log::UpdateLogEntry::new(
+                    "package",
+                    0,
+                    1,
+                    vec![error_line.clone()],
+                ),
+            );
+
             // Notify UI of error only if not silent update
             if !silent_update_enabled {
-                if let Some(window) = app_handle.get_webview_window("main") {
-                    let _ = window.emit(
-                        "operation-output",
-                        serde_json::json!({
-                            "line": error_line,
-                            "source": "stderr"
-                        }),
-                    );
-
-                    let _ = window.emit(
-                        "operation-finished",
-                        serde_json::json!({
-                            "success": false,
-                            "message": format!("Automatic package update failed: {}", e)
-                        }),
-                    );
-                }
+                emit_finished(
+                    app_handle,
+                    &[(error_line, false)],
+                    false,
+                    format!("Automatic package update failed: {}", e),
+                );
             }
+            (0, 1)
         }
     }
 }
 
-fn parse_update_interval(interval_raw: &str) -> Option<u64> {
+/// Converts an interval setting to seconds; `None` means auto-update is disabled.
+pub(crate) fn parse_update_interval(interval_raw: &str) -> Option<u64> {
     match interval_raw {
         "24h" | "1d" => Some(86400), // 24 hours
         "7d" | "1w" => Some(604800), // 7 days
@@ -270,3 +677,66 @@ fn parse_update_interval(interval_raw: &str) -> Option<u64> {
         numeric => numeric.parse::<u64>().ok(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::bucket_install::BucketInstallResult;
+
+    fn bucket_result(name: &str, success: bool, changed: bool) -> BucketInstallResult {
+        BucketInstallResult {
+            success,
+            message: String::new(),
+            bucket_name: name.to_string(),
+            bucket_path: None,
+            manifest_count: None,
+            commits_pulled: if changed { 3 } else { 0 },
+            changed,
+        }
+    }
+
+    #[test]
+    fn test_should_run_when_never_run() {
+        assert!(should_run(0, 3600, 1_000));
+    }
+
+    #[test]
+    fn test_should_run_once_interval_elapsed() {
+        assert!(!should_run(10_000, 3600, 10_000 + 3599));
+        assert!(should_run(10_000, 3600, 10_000 + 3600));
+        assert!(should_run(10_000, 3600, 10_000 + 7200));
+    }
+
+    #[test]
+    fn test_parse_update_interval() {
+        assert_eq!(parse_update_interval("off"), None);
+        assert_eq!(parse_update_interval("6h"), Some(21600));
+        assert_eq!(parse_update_interval("1w"), Some(604800));
+        assert_eq!(parse_update_interval("custom:900"), Some(900));
+        assert_eq!(parse_update_interval("1800"), Some(1800));
+        assert_eq!(parse_update_interval("soon"), None);
+    }
+
+    #[test]
+    fn test_cycle_report_counts_bucket_results() {
+        let report = CycleReport::from_bucket_results(&[
+            bucket_result("main", true, true),
+            bucket_result("extras", true, false),
+            bucket_result("broken", false, false),
+        ]);
+        assert_eq!(report.buckets_total, 3);
+        assert_eq!(report.buckets_succeeded, 2);
+        assert_eq!(report.buckets_changed, 1);
+        assert!(!report.is_success());
+    }
+
+    #[test]
+    fn test_cycle_report_success_requires_packages_to_succeed() {
+        let mut report = CycleReport::from_bucket_results(&[bucket_result("main", true, true)]);
+        assert!(report.is_success());
+
+        report.packages_updated = 2;
+        report.packages_failed = 1;
+        assert!(!report.is_success());
+    }
+}
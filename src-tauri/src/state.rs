@@ -1,10 +1,11 @@
 use crate::models::ScoopPackage;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::RwLock;
 use std::time::{SystemTime, UNIX_EPOCH};
-use tokio::sync::Mutex;
+use tokio::sync::{Mutex, Notify};
 
 #[derive(Clone)]
 pub struct InstalledPackagesCache {
@@ -18,6 +19,32 @@ pub struct PackageVersionsCache {
     pub versions_map: HashMap<String, Vec<String>>, // package_name -> list of version dirs
 }
 
+/// Cached result of `get_disk_usage`, valid while the installed packages fingerprint
+/// is unchanged.
+#[derive(Clone, Debug)]
+pub struct DiskUsageCache {
+    pub fingerprint: String, // Same fingerprint as installed packages cache
+    pub report: crate::commands::doctor::disk_usage::DiskUsageReport,
+}
+
+/// A package operation that is currently running.
+#[derive(Serialize, Clone, Debug)]
+pub struct ActiveOperation {
+    pub operation_id: String,
+    pub op_type: String,
+    pub target: String,
+    /// Unix timestamp (ms) when the operation started.
+    pub started_at: u64,
+}
+
+/// Duration of one phase of the startup sequence.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct StartupPhase {
+    pub name: String,
+    pub duration_ms: u64,
+}
+
 /// Shared application state managed by Tauri.
 pub struct AppState {
     /// The resolved path to the Scoop installation directory.
@@ -26,8 +53,26 @@ pub struct AppState {
     pub installed_packages: Mutex<Option<InstalledPackagesCache>>,
     /// A cache for package versions, invalidated when installed packages change
     pub package_versions: Mutex<Option<PackageVersionsCache>>,
+    /// A cache for the disk usage report, invalidated when installed packages change
+    pub disk_usage: Mutex<Option<DiskUsageCache>>,
     /// Timestamp (ms) of the last installed packages refresh to prevent rapid consecutive calls
     last_refresh_time: AtomicU64,
+    /// Fingerprint of the last completed installed packages scan (survives cache invalidation)
+    last_installed_fingerprint: RwLock<Option<String>>,
+    /// Set when the application is shutting down; background tasks must stop writing settings
+    shutdown_requested: AtomicBool,
+    /// True while the background scheduler is running an update (and may write settings)
+    scheduler_busy: AtomicBool,
+    /// Held for the duration of any bucket update so manual and scheduled runs never overlap
+    pub bucket_update_lock: Mutex<()>,
+    /// Wakes the scheduler loop early when one of its settings changes
+    pub scheduler_wakeup: Notify,
+    /// Operations started through `execute_package_operation`, keyed by operation id
+    active_operations: RwLock<HashMap<String, ActiveOperation>>,
+    /// Phases of the current startup sequence, in the order they completed
+    startup_timings: RwLock<Vec<StartupPhase>>,
+    /// Watcher on the apps directory; dropping it stops watching
+    pub apps_watcher: std::sync::Mutex<Option<notify::RecommendedWatcher>>,
 }
 
 impl AppState {
@@ -37,7 +82,16 @@ impl AppState {
             scoop_path: RwLock::new(initial_scoop_path),
             installed_packages: Mutex::new(None),
             package_versions: Mutex::new(None),
+            disk_usage: Mutex::new(None),
             last_refresh_time: AtomicU64::new(0),
+            last_installed_fingerprint: RwLock::new(None),
+            shutdown_requested: AtomicBool::new(false),
+            scheduler_busy: AtomicBool::new(false),
+            bucket_update_lock: Mutex::new(()),
+            scheduler_wakeup: Notify::new(),
+            active_operations: RwLock::new(HashMap::new()),
+            startup_timings: RwLock::new(Vec::new()),
+            apps_watcher: std::sync::Mutex::new(None),
         }
     }
 
@@ -51,6 +105,73 @@ impl AppState {
         *self.scoop_path.write().unwrap() = new_path;
     }
 
+    /// Signals background tasks that the application is shutting down.
+    pub fn request_shutdown(&self) {
+        self.shutdown_requested.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns true once shutdown has been requested.
+    pub fn is_shutting_down(&self) -> bool {
+        self.shutdown_requested.load(Ordering::SeqCst)
+    }
+
+    /// Marks whether the scheduler is currently in the middle of an update run.
+    pub fn set_scheduler_busy(&self, busy: bool) {
+        self.scheduler_busy.store(busy, Ordering::SeqCst);
+    }
+
+    /// Returns true while the scheduler is in the middle of an update run.
+    pub fn is_scheduler_busy(&self) -> bool {
+        self.scheduler_busy.load(Ordering::SeqCst)
+    }
+
+    /// Makes the scheduler re-read its settings without waiting for the current sleep.
+    pub fn wake_scheduler(&self) {
+        self.scheduler_wakeup.notify_one();
+    }
+
+    /// Registers a running operation so it can be listed (and cancelled) by the UI.
+    pub fn begin_operation(&self, operation: ActiveOperation) {
+        self.active_operations
+            .write()
+            .unwrap()
+            .insert(operation.operation_id.clone(), operation);
+    }
+
+    /// Removes a finished operation from the registry.
+    pub fn end_operation(&self, operation_id: &str) {
+        self.active_operations.write().unwrap().remove(operation_id);
+    }
+
+    /// Returns the currently running operations, oldest first.
+    pub fn active_operations(&self) -> Vec<ActiveOperation> {
+        let mut operations: Vec<ActiveOperation> =
+            self.active_operations.read().unwrap().values().cloned().collect();
+        operations.sort_by_key(|op| op.started_at);
+        operations
+    }
+
+    /// Appends a completed startup phase, returning its 1-based position.
+    pub fn push_startup_phase(&self, phase: StartupPhase) -> usize {
+        let mut timings = self.startup_timings.write().unwrap();
+        timings.push(phase);
+        timings.len()
+    }
+
+    /// Returns the startup phases recorded so far.
+    pub fn startup_timings(&self) -> Vec<StartupPhase> {
+        self.startup_timings.read().unwrap().clone()
+    }
+
+    /// Records the fingerprint of a fresh installed packages scan.
+    /// Returns true if a previous fingerprint existed and differs from the new one.
+    pub fn record_installed_fingerprint(&self, fingerprint: &str) -> bool {
+        let mut guard = self.last_installed_fingerprint.write().unwrap();
+        let changed = guard.as_deref().map_or(false, |prev| prev != fingerprint);
+        *guard = Some(fingerprint.to_string());
+        changed
+    }
+
     /// Gets the timestamp of the last installed packages refresh in milliseconds
     pub fn last_refresh_time(&self) -> u64 {
         self.last_refresh_time.load(Ordering::Relaxed)
@@ -289,7 +289,7 @@ pub fn resolve_scoop_root<R: Runtime>(app: AppHandle<R>) -> Result<PathBuf, Stri
             );
 
             if let Err(e) =
-                settings::set_scoop_path(app.clone(), best_path.to_string_lossy().to_string())
+                settings::persist_scoop_path(app.clone(), best_path.to_string_lossy().to_string())
             {
                 log::warn!(
                     "Failed to persist detected Scoop path '{}': {}",
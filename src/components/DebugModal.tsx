@@ -3,16 +3,23 @@ import { invoke } from "@tauri-apps/api/core";
 import { info, warn, error } from "@tauri-apps/plugin-log";
 import settingsStore from "../stores/settings";
 import Modal from "./common/Modal";
+import { errorMessage } from "../utils/errors";
 
 interface DebugInfo {
     timestamp: string;
     scoop_path: string;
     apps_dir_exists: boolean;
     app_count: number;
+    scoop_installed: boolean;
     cache_info: {
         cached_count: number;
         fingerprint: string | null;
     };
+    disk: {
+        apps_total_bytes: number;
+        cache_bytes: number;
+        total_bytes: number;
+    } | null;
 }
 
 const DebugModal = () => {
@@ -36,10 +43,10 @@ const DebugModal = () => {
             const logs = await invoke<string>("get_app_logs");
             setAppLogs(logs);
 
-            const logFile = await invoke<string>("read_app_log_file");
-            setLogFileContent(logFile);
+            const logFile = await invoke<{ content: string }>("read_app_log_file");
+            setLogFileContent(logFile.content);
         } catch (e) {
-            error(`Failed to fetch debug info: ${e}`);
+            error(`Failed to fetch debug info: ${errorMessage(e)}`);
         } finally {
             setIsLoading(false);
         }
@@ -5,6 +5,8 @@ import { relaunch } from "@tauri-apps/plugin-process";
 import { HardDrive, Folder, FileText, Trash2 } from "lucide-solid";
 import Card from "../../common/Card";
 import { t } from "../../../i18n";
+import { errorMessage } from "../../../utils/errors";
+import { useOperations, generateOperationId } from "../../../stores/operations";
 
 // Reusable action button component
 function ActionButton(props: {
@@ -67,6 +69,8 @@ export default function AppDataManagement() {
     const [clearCacheConfirm, setClearCacheConfirm] = createSignal<boolean>(false);
     const [clearCacheTimer, setClearCacheTimer] = createSignal<number | null>(null);
 
+    const { addOperation } = useOperations();
+
     onMount(async () => {
         try {
             const dataDir = await invoke<string>("get_app_data_dir");
@@ -76,8 +80,8 @@ export default function AppDataManagement() {
 
             setLoadError(null);
         } catch (error) {
-            const errorMessage = error instanceof Error ? error.message : String(error);
-            setLoadError(t("settings.appData.loadError") + ": " + errorMessage);
+            const message = errorMessage(error);
+            setLoadError(t("settings.appData.loadError") + ": " + message);
             console.error("Failed to load app data info:", error);
         } finally {
             setIsLoading(false);
@@ -119,8 +123,18 @@ export default function AppDataManagement() {
             setClearError(null);
             setIsClearing(true);
 
+            // Show the reset steps in the operation modal while they run
+            const operationId = generateOperationId("factory-reset");
+            addOperation({
+                id: operationId,
+                title: t("settings.appData.factoryReset"),
+                status: 'in-progress',
+                isMinimized: false,
+                output: []
+            });
+
             try {
-                await invoke("factory_reset");
+                await invoke("factory_reset", { operationId });
 
                 // setClearSuccess(true);
 
@@ -128,8 +142,8 @@ export default function AppDataManagement() {
                     await relaunch();
                 }, 1000);
             } catch (error) {
-                const errorMessage = error instanceof Error ? error.message : String(error);
-                setClearError(t("settings.appData.clearError") + ": " + errorMessage);
+                const message = errorMessage(error);
+                setClearError(t("settings.appData.clearError") + ": " + message);
                 setIsClearing(false);
             }
         } else {
@@ -164,8 +178,8 @@ export default function AppDataManagement() {
                 setIsClearingCache(false); // 退出loading状态
                 // Could show success message here if needed
             } catch (error) {
-                const errorMessage = error instanceof Error ? error.message : String(error);
-                setClearCacheError(t("settings.appData.clearCacheError") + ": " + errorMessage);
+                const message = errorMessage(error);
+                setClearCacheError(t("settings.appData.clearCacheError") + ": " + message);
                 setIsClearingCache(false);
             }
         } else {
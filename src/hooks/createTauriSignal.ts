@@ -1,5 +1,5 @@
 import { createSignal, createEffect, Signal, createRoot } from "solid-js";
-import { getSettingsStore } from "../stores/settings";
+import { getSettingsStore, persistSettingsStore } from "../stores/settings";
 
 // Note: We now share the store instance with settings.ts
 // All frontend data is stored in settings.json with namespaced keys
@@ -55,6 +55,7 @@ export function createTauriSignal<T>(
           if (legacyValue !== undefined && legacyValue !== null) {
             console.log(`createTauriSignal: Migrating legacy key "${key}" to "${namespacedKey}"`);
             await store.set(namespacedKey, legacyValue);
+            await persistSettingsStore();
             isLoaded = true;
             setValue(() => legacyValue as T);
           } else {
@@ -86,6 +87,7 @@ export function createTauriSignal<T>(
           try {
             const store = await getSettingsStore();
             await store.set(namespacedKey, currentValue);
+            await persistSettingsStore();
             console.log(`createTauriSignal: Successfully saved "${namespacedKey}"`);
           } catch (error) {
             console.error(`Error saving ${namespacedKey} to store:`, error);
@@ -3,7 +3,7 @@ import { invoke } from "@tauri-apps/api/core";
 import { ScoopPackage } from "../types/scoop";
 import { OperationNextStep } from "../types/operations";
 import installedPackagesStore from "../stores/installedPackagesStore";
-import { useOperations } from "../stores/operations";
+import { useOperations, generateOperationId } from "../stores/operations";
 
 interface UsePackageOperationsReturn {
   operationTitle: () => string | null;
@@ -45,8 +45,11 @@ const performInstall = (pkg: ScoopPackage) => {
     const title = `Installing ${pkg.name}`;
     setOperationTitle(title);
     
+    // The backend streams under this id, so it must be passed along
+    const operationId = generateOperationId(`install-${pkg.name}`);
+
     addOperation({
-        id: `install-${pkg.name}-${Math.floor(Date.now() / 1000)}`,
+        id: operationId,
         title,
         status: 'in-progress',
         isMinimized: false,
@@ -56,6 +59,7 @@ const performInstall = (pkg: ScoopPackage) => {
     invoke("install_package", {
         packageName: pkg.name,
         bucket: pkg.source,
+        operationId,
     }).catch((err) => {
         console.error(`Installation invocation failed for ${pkg.name}:`, err);
         setOperationNextStep(null);
@@ -91,8 +95,10 @@ const handleUninstall = (pkg: ScoopPackage) => {
     const title = `Uninstalling ${pkg.name}`;
     setOperationTitle(title);
 
+    const operationId = generateOperationId(`uninstall-${pkg.name}`);
+
     addOperation({
-        id: `uninstall-${pkg.name}-${Math.floor(Date.now() / 1000)}`,
+        id: operationId,
         title,
         status: 'in-progress',
         isMinimized: false,
@@ -102,6 +108,7 @@ const handleUninstall = (pkg: ScoopPackage) => {
     invoke("uninstall_package", {
         packageName: pkg.name,
         bucket: pkg.source,
+        operationId,
     }).catch((err) => {
         console.error(`Uninstallation invocation failed for ${pkg.name}:`, err);
         setOperationNextStep(null);
@@ -117,7 +124,7 @@ const handleUpdate = (pkg: ScoopPackage) => {
     const title = `Updating ${pkg.name}`;
     setOperationTitle(title);
 
-    const operationId = `update-${pkg.name}-${Math.floor(Date.now() / 1000)}`;
+    const operationId = generateOperationId(`update-${pkg.name}`);
     
     addOperation({
       id: operationId,
@@ -127,7 +134,7 @@ const handleUpdate = (pkg: ScoopPackage) => {
       output: []
     });
 
-    invoke("update_package", { packageName: pkg.name }).catch(err => {
+    invoke("update_package", { packageName: pkg.name, operationId }).catch(err => {
         console.error("Update invocation failed:", err);
     });
   };
@@ -141,15 +148,17 @@ const handleForceUpdate = (pkg: ScoopPackage) => {
     const title = `Force Updating ${pkg.name}`;
     setOperationTitle(title);
 
+    const operationId = generateOperationId(`force-update-${pkg.name}`);
+
     addOperation({
-        id: `force-update-${pkg.name}-${Math.floor(Date.now() / 1000)}`,
+        id: operationId,
         title,
         status: 'in-progress',
         isMinimized: false,
         output: []
     });
 
-    invoke("update_package", { packageName: pkg.name, force: true }).catch(err => {
+    invoke("update_package", { packageName: pkg.name, force: true, operationId }).catch(err => {
         console.error("Force update invocation failed:", err);
     });
 };
@@ -163,7 +172,7 @@ const handleUpdateAll = () => {
     const title = "Updating all packages";
     setOperationTitle(title);
 
-    const operationId = `update-all-${Math.floor(Date.now() / 1000)}`;
+    const operationId = generateOperationId("update-all");
     
     addOperation({
         id: operationId,
@@ -174,7 +183,7 @@ const handleUpdateAll = () => {
     });
 
     // 调用后端命令
-    invoke("update_all_packages").catch(err => {
+    invoke("update_all_packages", { operationId }).catch(err => {
         console.error("Update all invocation failed:", err);
     });
 };
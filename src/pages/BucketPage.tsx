@@ -250,7 +250,7 @@ function BucketPage() {
     }
   };
 
-  // Handle updating all buckets with limited concurrency
+  // Handle updating all buckets
   const handleUpdateAllBuckets = async () => {
     // If we're cancelling or already updating, don't start a new update
     if (updateState().status === 'updating') return;
@@ -287,43 +287,44 @@ function BucketPage() {
     currentUpdateOperation = cancellable;
 
     try {
-      // Create update promises using map directly
-      const updatePromises = gitBuckets.map(async (bucket, index) => {
-        // Check if cancelled before starting
-        if (cancelled) return Promise.resolve();
-
-        setUpdateState(prev => ({
-          ...prev,
-          message: `Updating ${bucket.name} (${index + 1}/${gitBuckets.length})`
-        }));
-
-        // Update bucket but don't refresh the bucket list yet
-        try {
-          const result = await handleUpdateBucket(bucket.name, false);
-
-          // Check if cancelled after update
-          if (cancelled) return Promise.resolve();
-
-          setUpdateState(prev => ({
-            ...prev,
-            current: prev.current + 1
-          }));
+      // A single backend run, since update_bucket refuses to run while another bucket
+      // update holds the lock
+      setUpdatingBuckets(new Set(gitBuckets.map(bucket => bucket.name)));
+      setUpdateState(prev => ({
+        ...prev,
+        message: `Updating ${gitBuckets.length} buckets`
+      }));
 
-          return result;
-        } catch (error) {
-          console.error(`Failed to update bucket ${bucket.name}:`, error);
+      let results: BucketUpdateResult[];
+      try {
+        results = await invoke<BucketUpdateResult[]>("update_buckets");
+      } finally {
+        setUpdatingBuckets(new Set<string>());
+      }
 
-          setUpdateState(prev => ({
-            ...prev,
-            current: prev.current + 1
-          }));
+      setUpdateState(prev => ({
+        ...prev,
+        current: prev.total
+      }));
 
-          return Promise.resolve();
-        }
-      });
+      for (const result of results) {
+        setUpdateResults(prev => ({
+          ...prev,
+          [result.bucket_name]: result.message
+        }));
 
-      // Execute all promises
-      await Promise.all(updatePromises);
+        // Clear result message after 2 seconds to avoid long display
+        const timerId = window.setTimeout(() => {
+          setUpdateResults(prev => {
+            const newResults = { ...prev };
+            delete newResults[result.bucket_name];
+            return newResults;
+          });
+          resultTimerIds.delete(result.bucket_name);
+        }, 2000);
+
+        resultTimerIds.set(result.bucket_name, timerId);
+      }
 
       // Check if cancelled before completing
       if (cancelled) return;
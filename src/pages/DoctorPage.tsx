@@ -12,6 +12,7 @@ import OperationModal from "../components/OperationModal";
 import installedPackagesStore from "../stores/installedPackagesStore";
 import { useOperations } from "../stores/operations";
 import { t } from "../i18n";
+import { errorMessage } from "../utils/errors";
 
 const CACHE_DIR = "cache";
 const SHIMS_DIR = "shims";
@@ -69,7 +70,7 @@ function DoctorPage() {
             const result = await invoke<CheckupItem[]>("run_scoop_checkup");
             setCheckupResult(result);
         } catch (err) {
-            const errorMsg = err instanceof Error ? err.message : String(err);
+            const errorMsg = errorMessage(err);
             console.error("Failed to run sfsu checkup:", errorMsg);
             setCheckupError("Could not run sfsu checkup. Please ensure 'sfsu' is installed and accessible in your PATH.");
             setCheckupResult([]);
@@ -106,7 +107,7 @@ function DoctorPage() {
             await runCheckup();
             installedPackagesStore.refetch();
         } catch (err) {
-            const errorMsg = err instanceof Error ? err.message : String(err);
+            const errorMsg = errorMessage(err);
             console.error(`Failed to install ${helperId}:`, errorMsg);
         } finally {
             setInstallingHelper(null);
@@ -132,7 +133,7 @@ function DoctorPage() {
             console.log(`Operation "${title}" completed successfully`);
         }).catch(err => {
             // Operation failed
-            const errorMsg = err instanceof Error ? err.message : String(err);
+            const errorMsg = errorMessage(err);
             console.error(`Operation "${title}" failed:`, errorMsg);
         });
         // Modal closure is handled by its own event
@@ -166,7 +167,7 @@ function DoctorPage() {
                 const scoopPath = await invoke<string>("get_scoop_path");
                 return `${scoopPath}\\${subPath}`;
             } catch (err) {
-                const errorMsg = err instanceof Error ? err.message : String(err);
+                const errorMsg = errorMessage(err);
                 console.error(`Failed to get scoop path for ${subPath}:`, errorMsg);
                 throw err;
             }
@@ -14,12 +14,18 @@ let storeInitialized = false;
 /// Get or initialize the shared store instance
 export async function getSettingsStore(): Promise<Store> {
   if (!globalStore) {
-    globalStore = await Store.load(STORE_NAME);
+    // Auto-save is off so the backend can write the file atomically, see persistSettingsStore
+    globalStore = await Store.load(STORE_NAME, { autoSave: false });
     console.log('Tauri store for frontend settings loaded successfully');
   }
   return globalStore;
 }
 
+/// Write the store to disk; it is loaded without auto-save
+export async function persistSettingsStore(): Promise<void> {
+  await invoke("save_settings_store");
+}
+
 interface Settings {
   virustotal: {
     enabled: boolean;
@@ -113,6 +119,7 @@ function createSettingsStore() {
         // Migrate data from localStorage to Tauri store
         const settingsData = JSON.parse(localStorageData);
         await globalStore!.set('settings', settingsData);
+        await persistSettingsStore();
         localStorage.removeItem('rscoop-settings'); // Clean up localStorage after migration
       }
     } catch (error) {
@@ -149,6 +156,7 @@ function createSettingsStore() {
       if (storeInstance) {
         try {
           await storeInstance.delete('settings');
+          await persistSettingsStore();
         } catch (error) {
           console.error('Error clearing settings during factory reset:', error);
         }
@@ -239,6 +247,7 @@ function createSettingsStore() {
           const storeInstance = await getSettingsStore();
           if (storeInstance) {
             await storeInstance.set('settings', updated);
+            await persistSettingsStore();
           }
         } catch (error) {
           console.error('Error saving settings to store:', error);
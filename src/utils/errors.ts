@@ -0,0 +1,36 @@
+/**
+ * Error returned by backend commands that use the structured `AppError` type.
+ */
+export interface AppError {
+  code: string;
+  message: string;
+}
+
+/**
+ * Checks whether a rejected invoke value is a structured `AppError`
+ * @param error - The value the command rejected with
+ * @returns true if the value has a `code` and a `message`
+ */
+export function isAppError(error: unknown): error is AppError {
+  return (
+    typeof error === "object" &&
+    error !== null &&
+    typeof (error as AppError).code === "string" &&
+    typeof (error as AppError).message === "string"
+  );
+}
+
+/**
+ * Extracts a human-readable message from a rejected invoke value
+ * @param error - An `Error`, a structured `AppError` or a plain string
+ * @returns The error message
+ */
+export function errorMessage(error: unknown): string {
+  if (error instanceof Error) {
+    return error.message;
+  }
+  if (isAppError(error)) {
+    return error.message;
+  }
+  return String(error);
+}